@@ -0,0 +1,192 @@
+//! Drives arbitrary sequences of lock / approve / refund / release calls
+//! against the escrow and checks the refund state machine after every step:
+//!
+//! * `remaining_amount` is never negative
+//! * refunded + released + remaining always equals the locked amount
+//! * `Released` and `Refunded` are final
+//! * the contract holds exactly the sum of all remaining amounts
+//!
+//! Run with `cargo +nightly fuzz run refund_sequence`.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use bounty_escrow::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus, RefundMode};
+use libfuzzer_sys::fuzz_target;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const BOUNTY_COUNT: usize = 4;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Lock {
+        bounty: u8,
+        amount: u16,
+        deadline_offset: u16,
+    },
+    ApproveRefund {
+        bounty: u8,
+        amount: u16,
+    },
+    PartialRefund {
+        bounty: u8,
+        amount: u16,
+    },
+    FullRefund {
+        bounty: u8,
+    },
+    Release {
+        bounty: u8,
+    },
+    AdvanceTime {
+        seconds: u16,
+    },
+}
+
+/// What the harness has observed for one bounty id
+#[derive(Default)]
+struct Observed {
+    locked: i128,
+    refunded: i128,
+    released: i128,
+    final_status: Option<EscrowStatus>,
+}
+
+fn bounty_id(bounty: u8) -> u64 {
+    (bounty as usize % BOUNTY_COUNT) as u64
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Long sequences would otherwise run out of the default test budget
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&depositor, &i128::MAX);
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+    escrow.init(&admin, &token_address, &None);
+    // Rate limiting is not what is under test here
+    escrow.set_whitelist(&depositor, &true);
+    escrow.set_whitelist(&admin, &true);
+
+    let mut seen: [Observed; BOUNTY_COUNT] = core::array::from_fn(|_| Observed::default());
+
+    for op in ops {
+        match op {
+            Op::Lock {
+                bounty,
+                amount,
+                deadline_offset,
+            } => {
+                let id = bounty_id(bounty);
+                let deadline = env.ledger().timestamp() + deadline_offset as u64;
+                let amount = amount as i128;
+                if escrow
+                    .try_lock_funds(&depositor, &id, &amount, &deadline)
+                    .is_ok()
+                {
+                    assert_eq!(seen[id as usize].locked, 0, "bounty locked twice");
+                    seen[id as usize].locked = amount;
+                }
+            }
+            Op::ApproveRefund { bounty, amount } => {
+                let _ = escrow.try_approve_refund(
+                    &bounty_id(bounty),
+                    &(amount as i128),
+                    &depositor,
+                    &RefundMode::Partial,
+                    &None::<String>,
+                );
+            }
+            Op::PartialRefund { bounty, amount } => {
+                let id = bounty_id(bounty);
+                let amount = amount as i128;
+                if escrow
+                    .try_refund(
+                        &id,
+                        &Some(amount),
+                        &None,
+                        &RefundMode::Partial,
+                        &None::<String>,
+                    )
+                    .is_ok()
+                {
+                    seen[id as usize].refunded += amount;
+                }
+            }
+            Op::FullRefund { bounty } => {
+                let id = bounty_id(bounty);
+                let before = escrow.try_get_escrow_info(&id);
+                if escrow
+                    .try_refund(&id, &None, &None, &RefundMode::Full, &None::<String>)
+                    .is_ok()
+                {
+                    seen[id as usize].refunded += before.unwrap().unwrap().remaining_amount;
+                }
+            }
+            Op::Release { bounty } => {
+                let id = bounty_id(bounty);
+                let before = escrow.try_get_escrow_info(&id);
+                if escrow.try_release_funds(&id, &contributor).is_ok() {
+                    seen[id as usize].released += before.unwrap().unwrap().remaining_amount;
+                }
+            }
+            Op::AdvanceTime { seconds } => {
+                let now = env.ledger().timestamp();
+                env.ledger().set_timestamp(now + seconds as u64);
+            }
+        }
+
+        let mut held = 0;
+        for (id, bounty) in seen.iter_mut().enumerate() {
+            let info = match escrow.try_get_escrow_info(&(id as u64)) {
+                Ok(Ok(info)) => info,
+                _ => {
+                    assert_eq!(bounty.locked, 0, "locked bounty disappeared");
+                    continue;
+                }
+            };
+
+            assert!(info.remaining_amount >= 0, "negative remaining amount");
+            assert!(
+                bounty.refunded + bounty.released <= bounty.locked,
+                "paid out more than was locked"
+            );
+            assert_eq!(
+                info.remaining_amount,
+                bounty.locked - bounty.refunded - bounty.released,
+                "conservation violated"
+            );
+
+            if let Some(final_status) = &bounty.final_status {
+                assert_eq!(&info.status, final_status, "left a final state");
+            }
+            match info.status {
+                EscrowStatus::Released | EscrowStatus::Refunded => {
+                    assert_eq!(info.remaining_amount, 0, "final state still holds funds");
+                    bounty.final_status = Some(info.status.clone());
+                }
+                EscrowStatus::Locked => {
+                    assert_eq!(bounty.refunded + bounty.released, 0, "paid out while Locked")
+                }
+                EscrowStatus::PartiallyRefunded | EscrowStatus::PartiallyReleased => {
+                    assert!(info.remaining_amount > 0, "partial state with nothing left")
+                }
+            }
+            held += info.remaining_amount;
+        }
+        assert_eq!(token.balance(&contract_id), held, "contract balance drifted");
+    }
+});
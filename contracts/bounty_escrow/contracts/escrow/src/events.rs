@@ -25,6 +25,33 @@
 
 use soroban_sdk::{contracttype, symbol_short, Address, Env};
 
+// ============================================================================
+// Event Verbosity
+// ============================================================================
+
+/// Controls how many events the lifecycle hooks emit.
+///
+/// * `Minimal` - Only the essential `FundsLocked` / `FundsReleased` /
+///   `FundsRefunded` events; no enhanced, activity or metric events
+/// * `Standard` - Enhanced lifecycle events and metrics, without status-change
+///   and activity tracking events
+/// * `Full` - Everything (default)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventVerbosity {
+    Minimal,
+    Standard,
+    Full,
+}
+
+/// Returns the configured event verbosity, defaulting to `Full`.
+pub fn get_event_verbosity(env: &Env) -> EventVerbosity {
+    env.storage()
+        .instance()
+        .get(&crate::DataKey::EventVerbosity)
+        .unwrap_or(EventVerbosity::Full)
+}
+
 // ============================================================================
 // Contract Initialization Event
 // ============================================================================
@@ -287,6 +314,7 @@ pub fn _emit_funds_refunded(env: &Env, event: FundsRefunded) {
     env.events().publish(topics, event.clone());
 }
 
+#[cfg(feature = "fees")]
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FeeOperationType {
@@ -294,6 +322,7 @@ pub enum FeeOperationType {
     Release,
 }
 
+#[cfg(feature = "fees")]
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct FeeCollected {
@@ -304,11 +333,28 @@ pub struct FeeCollected {
     pub timestamp: u64,
 }
 
+#[cfg(feature = "fees")]
 pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
     let topics = (symbol_short!("fee"),);
     env.events().publish(topics, event.clone());
 }
 
+#[cfg(feature = "fees")]
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AccruedFeesWithdrawn {
+    pub amount: i128,
+    pub remaining: i128,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+#[cfg(feature = "fees")]
+pub fn emit_accrued_fees_withdrawn(env: &Env, event: AccruedFeesWithdrawn) {
+    let topics = (symbol_short!("fee_wd"),);
+    env.events().publish(topics, event.clone());
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BatchFundsLocked {
@@ -322,6 +368,7 @@ pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
     env.events().publish(topics, event.clone());
 }
 
+#[cfg(feature = "fees")]
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct FeeConfigUpdated {
@@ -329,14 +376,104 @@ pub struct FeeConfigUpdated {
     pub release_fee_rate: i128,
     pub fee_recipient: Address,
     pub fee_enabled: bool,
+    pub rounding_mode: crate::FeeRoundingMode,
     pub timestamp: u64,
 }
 
+#[cfg(feature = "fees")]
 pub fn emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
     let topics = (symbol_short!("fee_cfg"),);
     env.events().publish(topics, event.clone());
 }
 
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundGracePeriodUpdated {
+    pub grace_period: u64,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_grace_period_updated(env: &Env, event: RefundGracePeriodUpdated) {
+    let topics = (symbol_short!("grace"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// A refund record that was not stored because the escrow's history is full
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundRecordOverflow {
+    pub bounty_id: u64,
+    pub record: crate::RefundRecord,
+}
+
+pub fn emit_refund_record_overflow(env: &Env, event: RefundRecordOverflow) {
+    let topics = (symbol_short!("rf_over"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// A refund clamped to the contract's token balance; `shortfall` stays
+/// recorded in the escrow's `remaining_amount`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundShortfall {
+    pub bounty_id: u64,
+    pub requested: i128,
+    pub refunded: i128,
+    pub shortfall: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_shortfall(env: &Env, event: RefundShortfall) {
+    let topics = (symbol_short!("rf_short"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// An outstanding refund approval that a newer `approve_refund` overwrote
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundApprovalReplaced {
+    pub bounty_id: u64,
+    pub previous: crate::RefundApproval,
+    pub replacement: crate::RefundApproval,
+}
+
+pub fn emit_refund_approval_replaced(env: &Env, event: RefundApprovalReplaced) {
+    let topics = (symbol_short!("rf_repl"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// A timelocked release requested via `request_release`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseRequested {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub requested_by: Address,
+    pub executable_after: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_requested(env: &Env, event: ReleaseRequested) {
+    let topics = (symbol_short!("rel_req"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// A pending release request withdrawn via `cancel_release_request`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseRequestCancelled {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub cancelled_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_request_cancelled(env: &Env, event: ReleaseRequestCancelled) {
+    let topics = (symbol_short!("rel_cncl"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BatchFundsReleased {
@@ -379,6 +516,38 @@ pub fn emit_contract_unpaused(env: &Env, event: ContractUnpaused) {
     env.events().publish(topics, event.clone());
 }
 
+/// Event emitted when the contract is permanently closed to new locks.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContractSunset {
+    pub sunset_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_sunset(env: &Env, event: ContractSunset) {
+    let topics = (symbol_short!("sunset"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a settled escrow is removed from storage.
+/// Last on-chain record of the escrow once its state is deleted.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EscrowArchived {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub final_status: crate::EscrowStatus,
+    pub settled_at: u64,
+    pub archived_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_archived(env: &Env, event: EscrowArchived) {
+    let topics = (symbol_short!("archived"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
 /// Event emitted when emergency withdrawal occurs.
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -393,3 +562,71 @@ pub fn emit_emergency_withdrawal(env: &Env, event: EmergencyWithdrawal) {
     let topics = (symbol_short!("ewith"),);
     env.events().publish(topics, event.clone());
 }
+
+// ============================================================================
+// Anti-Abuse Configuration Events
+// ============================================================================
+
+/// Event emitted when the rate limiting configuration is updated.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AntiAbuseConfigUpdated {
+    pub window_size: u64,
+    pub max_operations: u32,
+    pub cooldown_period: u64,
+    pub mode: crate::anti_abuse::RateLimitMode,
+    pub by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_anti_abuse_config_updated(env: &Env, event: AntiAbuseConfigUpdated) {
+    let topics = (symbol_short!("abuse"), symbol_short!("config"));
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when an address is added to or removed from the rate limit whitelist.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct WhitelistChanged {
+    pub address: Address,
+    pub whitelisted: bool,
+    pub by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_whitelist_changed(env: &Env, event: WhitelistChanged) {
+    let topics = (symbol_short!("abuse"), symbol_short!("wlist"));
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Bounty Freeze Events
+// ============================================================================
+
+/// Event emitted when an admin freezes a single bounty.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyFrozen {
+    pub bounty_id: u64,
+    pub frozen_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_frozen(env: &Env, event: BountyFrozen) {
+    let topics = (symbol_short!("freeze"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when an admin unfreezes a bounty.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyUnfrozen {
+    pub bounty_id: u64,
+    pub unfrozen_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_unfrozen(env: &Env, event: BountyUnfrozen) {
+    let topics = (symbol_short!("unfreeze"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
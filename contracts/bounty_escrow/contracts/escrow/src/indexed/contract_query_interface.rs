@@ -1,5 +1,6 @@
 use crate::indexed::indexed_storage::{BountyStatus, IndexedBounty, PaginatedResult, QueryFilter};
 use crate::indexed::query_functions;
+use crate::Error;
 use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
 
 #[contract]
@@ -7,14 +8,18 @@ pub struct EscrowQueryContract;
 
 #[contractimpl]
 impl EscrowQueryContract {
-    /// Query bounties with flexible filtering and pagination
+    /// Query bounties with flexible filtering and pagination.
+    /// Filters with an inverted range fail with `InvalidQueryFilter`.
     pub fn query_bounties(
         env: Env,
         filter: QueryFilter,
         page: u32,
         page_size: u32,
-    ) -> PaginatedResult {
-        query_functions::query_bounties(&env, filter, page, page_size)
+    ) -> Result<PaginatedResult, Error> {
+        filter.validate()?;
+        Ok(query_functions::query_bounties(
+            &env, filter, page, page_size,
+        ))
     }
 
     /// Get a single bounty by ID
@@ -42,6 +47,11 @@ impl EscrowQueryContract {
         query_functions::get_bounties_by_date_range(&env, from, to)
     }
 
+    /// Get locked bounties whose deadline falls within a time range
+    pub fn get_escrows_expiring_between(env: Env, from: u64, to: u64) -> Vec<IndexedBounty> {
+        query_functions::get_escrows_expiring_between(&env, from, to)
+    }
+
     /// Get the most recent bounties
     pub fn get_recent_bounties(env: Env, count: u32) -> Vec<IndexedBounty> {
         query_functions::get_recent_bounties(&env, count)
@@ -59,66 +69,43 @@ impl EscrowQueryContract {
         page: u32,
         page_size: u32,
     ) -> PaginatedResult {
-        let filter = QueryFilter {
-            status: BountyStatus::None,
-            depositor: Some(depositor),
-            min_amount: None,
-            max_amount: None,
-            from_timestamp: None,
-            to_timestamp: None,
-        };
+        let filter = QueryFilter::builder().depositor(depositor).build();
         query_functions::query_bounties(&env, filter, page, page_size)
     }
 
     /// Get all active (locked) bounties with pagination
     pub fn get_active_bounties(env: Env, page: u32, page_size: u32) -> PaginatedResult {
-        let filter = QueryFilter {
-            status: BountyStatus::Locked,
-            depositor: None,
-            min_amount: None,
-            max_amount: None,
-            from_timestamp: None,
-            to_timestamp: None,
-        };
+        let filter = QueryFilter::builder().status(BountyStatus::Locked).build();
         query_functions::query_bounties(&env, filter, page, page_size)
     }
 
     /// Get all completed (released) bounties with pagination
     pub fn get_completed_bounties(env: Env, page: u32, page_size: u32) -> PaginatedResult {
-        let filter = QueryFilter {
-            status: BountyStatus::Released,
-            depositor: None,
-            min_amount: None,
-            max_amount: None,
-            from_timestamp: None,
-            to_timestamp: None,
-        };
+        let filter = QueryFilter::builder()
+            .status(BountyStatus::Released)
+            .build();
         query_functions::query_bounties(&env, filter, page, page_size)
     }
 
     /// Get all refunded bounties with pagination
     pub fn get_refunded_bounties(env: Env, page: u32, page_size: u32) -> PaginatedResult {
-        let filter = QueryFilter {
-            status: BountyStatus::Refunded,
-            depositor: None,
-            min_amount: None,
-            max_amount: None,
-            from_timestamp: None,
-            to_timestamp: None,
-        };
+        let filter = QueryFilter::builder()
+            .status(BountyStatus::Refunded)
+            .build();
         query_functions::query_bounties(&env, filter, page, page_size)
     }
 
     /// Get all partially released bounties with pagination
     pub fn get_partially_released_bounties(env: Env, page: u32, page_size: u32) -> PaginatedResult {
-        let filter = QueryFilter {
-            status: BountyStatus::PartiallyReleased,
-            depositor: None,
-            min_amount: None,
-            max_amount: None,
-            from_timestamp: None,
-            to_timestamp: None,
-        };
+        let filter = QueryFilter::builder()
+            .status(BountyStatus::PartiallyReleased)
+            .build();
+        query_functions::query_bounties(&env, filter, page, page_size)
+    }
+
+    /// Get all frozen bounties with pagination
+    pub fn get_frozen_bounties(env: Env, page: u32, page_size: u32) -> PaginatedResult {
+        let filter = QueryFilter::builder().status(BountyStatus::Frozen).build();
         query_functions::query_bounties(&env, filter, page, page_size)
     }
 
@@ -206,6 +193,8 @@ impl EscrowQueryContract {
             max_amount,
             from_timestamp: from_date,
             to_timestamp: to_date,
+            deadline_from: None,
+            deadline_to: None,
         };
         query_functions::query_bounties(&env, filter, page, page_size)
     }
@@ -234,14 +223,10 @@ impl EscrowQueryContract {
         page: u32,
         page_size: u32,
     ) -> PaginatedResult {
-        let filter = QueryFilter {
-            status: status,
-            depositor: Some(depositor),
-            min_amount: None,
-            max_amount: None,
-            from_timestamp: None,
-            to_timestamp: None,
-        };
+        let filter = QueryFilter::builder()
+            .status(status)
+            .depositor(depositor)
+            .build();
         query_functions::query_bounties(&env, filter, page, page_size)
     }
 }
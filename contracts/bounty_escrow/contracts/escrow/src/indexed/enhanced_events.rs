@@ -1,8 +1,48 @@
 use crate::RefundMode;
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, String, Symbol, Vec};
 
 const EVENT_VERSION: u32 = 1;
 
+/// First-position topic symbols of every event the contract emits.
+/// Keep in sync with the emitters and bump `EVENT_VERSION` when it changes.
+pub fn event_topics(env: &Env) -> Vec<Symbol> {
+    vec![
+        env,
+        symbol_short!("init"),
+        symbol_short!("f_lock"),
+        symbol_short!("f_rel"),
+        symbol_short!("f_ref"),
+        symbol_short!("fee"),
+        symbol_short!("fee_wd"),
+        symbol_short!("fee_cfg"),
+        symbol_short!("b_lock"),
+        symbol_short!("b_rel"),
+        symbol_short!("grace"),
+        symbol_short!("rf_over"),
+        symbol_short!("rf_repl"),
+        symbol_short!("rf_short"),
+        symbol_short!("rel_req"),
+        symbol_short!("rel_cncl"),
+        symbol_short!("pause"),
+        symbol_short!("unpause"),
+        symbol_short!("sunset"),
+        symbol_short!("archived"),
+        symbol_short!("ewith"),
+        symbol_short!("abuse"),
+        symbol_short!("freeze"),
+        symbol_short!("unfreeze"),
+        symbol_short!("status"),
+        symbol_short!("activity"),
+        symbol_short!("dl_ext"),
+        symbol_short!("amt_inc"),
+        symbol_short!("reassign"),
+        symbol_short!("dep_xfer"),
+        symbol_short!("notify"),
+        symbol_short!("error"),
+        symbol_short!("metric"),
+    ]
+}
+
 // ============================================================================
 // Event Metadata
 // ============================================================================
@@ -138,6 +178,8 @@ pub enum ActivityType {
     Cancelled,
     DeadlineExtended,
     AmountIncreased,
+    Reassigned,
+    DepositorTransferred,
 }
 
 #[contracttype]
@@ -358,6 +400,36 @@ pub fn _emit_bounty_amount_increased(env: &Env, event: BountyAmountIncreased) {
     env.events().publish(topics, event.clone());
 }
 
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyReassigned {
+    pub old_bounty_id: u64,
+    pub new_bounty_id: u64,
+    pub reassigned_by: Address,
+    pub timestamp: u64,
+    pub metadata: EventMetadata,
+}
+
+pub fn _emit_bounty_reassigned(env: &Env, event: BountyReassigned) {
+    let topics = (symbol_short!("reassign"), event.old_bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DepositorTransferred {
+    pub bounty_id: u64,
+    pub old_depositor: Address,
+    pub new_depositor: Address,
+    pub timestamp: u64,
+    pub metadata: EventMetadata,
+}
+
+pub fn _emit_depositor_transferred(env: &Env, event: DepositorTransferred) {
+    let topics = (symbol_short!("dep_xfer"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
 // ============================================================================
 // Error/Warning Events
 // ============================================================================
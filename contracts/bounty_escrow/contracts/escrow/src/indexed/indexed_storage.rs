@@ -1,3 +1,4 @@
+use crate::Error;
 use soroban_sdk::{contracttype, Address, Env, Vec};
 
 // ============================================================================
@@ -24,6 +25,8 @@ pub enum BountyStatus {
     Released,
     Refunded,
     PartiallyReleased,
+    /// Still holding funds, but release and refund are blocked by an admin freeze
+    Frozen,
 }
 
 #[contracttype]
@@ -35,6 +38,94 @@ pub struct QueryFilter {
     pub max_amount: Option<i128>,
     pub from_timestamp: Option<u64>,
     pub to_timestamp: Option<u64>,
+    pub deadline_from: Option<u64>,
+    pub deadline_to: Option<u64>,
+}
+
+impl QueryFilter {
+    /// Start a filter that matches every bounty
+    pub fn builder() -> QueryFilterBuilder {
+        QueryFilterBuilder {
+            filter: QueryFilter {
+                status: BountyStatus::None,
+                depositor: None,
+                min_amount: None,
+                max_amount: None,
+                from_timestamp: None,
+                to_timestamp: None,
+                deadline_from: None,
+                deadline_to: None,
+            },
+        }
+    }
+
+    /// Reject filters whose ranges can never match, instead of silently
+    /// returning no results
+    pub fn validate(&self) -> Result<(), Error> {
+        fn inverted<T: PartialOrd>(from: &Option<T>, to: &Option<T>) -> bool {
+            matches!((from, to), (Some(from), Some(to)) if from > to)
+        }
+
+        if inverted(&self.min_amount, &self.max_amount)
+            || inverted(&self.from_timestamp, &self.to_timestamp)
+            || inverted(&self.deadline_from, &self.deadline_to)
+        {
+            return Err(Error::InvalidQueryFilter);
+        }
+        Ok(())
+    }
+}
+
+/// Chained construction of a `QueryFilter`; unset criteria match everything
+#[derive(Clone, Debug)]
+pub struct QueryFilterBuilder {
+    filter: QueryFilter,
+}
+
+impl QueryFilterBuilder {
+    pub fn status(mut self, status: BountyStatus) -> Self {
+        self.filter.status = status;
+        self
+    }
+
+    pub fn depositor(mut self, depositor: Address) -> Self {
+        self.filter.depositor = Some(depositor);
+        self
+    }
+
+    pub fn min_amount(mut self, min_amount: i128) -> Self {
+        self.filter.min_amount = Some(min_amount);
+        self
+    }
+
+    pub fn max_amount(mut self, max_amount: i128) -> Self {
+        self.filter.max_amount = Some(max_amount);
+        self
+    }
+
+    pub fn created_from(mut self, from_timestamp: u64) -> Self {
+        self.filter.from_timestamp = Some(from_timestamp);
+        self
+    }
+
+    pub fn created_to(mut self, to_timestamp: u64) -> Self {
+        self.filter.to_timestamp = Some(to_timestamp);
+        self
+    }
+
+    pub fn deadline_from(mut self, deadline_from: u64) -> Self {
+        self.filter.deadline_from = Some(deadline_from);
+        self
+    }
+
+    pub fn deadline_to(mut self, deadline_to: u64) -> Self {
+        self.filter.deadline_to = Some(deadline_to);
+        self
+    }
+
+    pub fn build(self) -> QueryFilter {
+        self.filter
+    }
 }
 
 #[contracttype]
@@ -47,6 +138,15 @@ pub struct PaginatedResult {
     pub has_more: bool,
 }
 
+/// Aggregated payouts received by a single contributor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorStats {
+    pub total_bounties: u32,
+    pub total_payouts: u32,
+    pub total_received_value: i128,
+}
+
 // ============================================================================
 // Storage Index Keys
 // ============================================================================
@@ -56,6 +156,11 @@ pub const STATUS_INDEX: &str = "SIDX";
 pub const DEPOSITOR_INDEX: &str = "DIDX";
 pub const AMOUNT_INDEX: &str = "AIDX";
 pub const TIMESTAMP_INDEX: &str = "TIDX";
+pub const CONTRIBUTOR_INDEX: &str = "CIDX";
+pub const CONTRIBUTOR_STATS: &str = "CSTS";
+pub const STATUS_COUNT: &str = "SCNT";
+pub const DEADLINE_INDEX: &str = "DLIX";
+pub const DEADLINE_DAYS: &str = "DLDY";
 
 // ============================================================================
 // Index Management Functions
@@ -70,6 +175,7 @@ pub fn index_bounty(env: &Env, bounty: IndexedBounty) {
     // Status index: (status, bounty_id) -> true
     let status_key = (STATUS_INDEX, bounty.status.clone(), bounty.bounty_id);
     env.storage().persistent().set(&status_key, &true);
+    adjust_status_count(env, &bounty.status, 1);
 
     // Depositor index: (depositor, bounty_id) -> true
     let depositor_key = (DEPOSITOR_INDEX, bounty.depositor.clone(), bounty.bounty_id);
@@ -86,6 +192,11 @@ pub fn index_bounty(env: &Env, bounty: IndexedBounty) {
     let timestamp_bucket = bounty.created_at / 86400;
     let timestamp_key = (TIMESTAMP_INDEX, timestamp_bucket, bounty.bounty_id);
     env.storage().persistent().set(&timestamp_key, &true);
+
+    // Deadline index: only bounties still holding funds are due
+    if is_open(&bounty.status) {
+        add_to_deadline_index(env, bounty.bounty_id, bounty.deadline);
+    }
 }
 
 /// Updates the status of a bounty and re-indexes accordingly
@@ -95,6 +206,15 @@ pub fn update_bounty_status(env: &Env, bounty_id: u64, new_status: BountyStatus)
         // Remove old status index entry
         let old_status_key = (STATUS_INDEX, bounty.status.clone(), bounty_id);
         env.storage().persistent().remove(&old_status_key);
+        adjust_status_count(env, &bounty.status, -1);
+        adjust_status_count(env, &new_status, 1);
+
+        // Settled bounties leave the deadline index
+        match (is_open(&bounty.status), is_open(&new_status)) {
+            (true, false) => remove_from_deadline_index(env, bounty_id, bounty.deadline),
+            (false, true) => add_to_deadline_index(env, bounty_id, bounty.deadline),
+            _ => {}
+        }
 
         // Update bounty
         bounty.status = new_status.clone();
@@ -138,10 +258,35 @@ pub fn update_bounty_amount(env: &Env, bounty_id: u64, new_amount: i128) {
     }
 }
 
+/// Moves a bounty to a new depositor in the depositor index
+pub fn update_bounty_depositor(env: &Env, bounty_id: u64, new_depositor: &Address) {
+    let key = (BOUNTY_INDEX, bounty_id);
+    if let Some(mut bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
+        // Remove old depositor index entry
+        let old_depositor_key = (DEPOSITOR_INDEX, bounty.depositor.clone(), bounty_id);
+        env.storage().persistent().remove(&old_depositor_key);
+
+        // Update bounty
+        bounty.depositor = new_depositor.clone();
+        bounty.updated_at = env.ledger().timestamp();
+
+        // Save updated bounty
+        env.storage().persistent().set(&key, &bounty);
+
+        // Add new depositor index entry
+        let new_depositor_key = (DEPOSITOR_INDEX, new_depositor.clone(), bounty_id);
+        env.storage().persistent().set(&new_depositor_key, &true);
+    }
+}
+
 /// Updates the deadline of a bounty
 pub fn update_bounty_deadline(env: &Env, bounty_id: u64, new_deadline: u64) {
     let key = (BOUNTY_INDEX, bounty_id);
     if let Some(mut bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
+        if is_open(&bounty.status) {
+            remove_from_deadline_index(env, bounty_id, bounty.deadline);
+            add_to_deadline_index(env, bounty_id, new_deadline);
+        }
         bounty.deadline = new_deadline;
         bounty.updated_at = env.ledger().timestamp();
         env.storage().persistent().set(&key, &bounty);
@@ -155,6 +300,7 @@ pub fn remove_bounty(env: &Env, bounty_id: u64) {
         // Remove from all indices
         let status_key = (STATUS_INDEX, bounty.status.clone(), bounty_id);
         env.storage().persistent().remove(&status_key);
+        adjust_status_count(env, &bounty.status, -1);
 
         let depositor_key = (DEPOSITOR_INDEX, bounty.depositor.clone(), bounty_id);
         env.storage().persistent().remove(&depositor_key);
@@ -167,11 +313,64 @@ pub fn remove_bounty(env: &Env, bounty_id: u64) {
         let timestamp_key = (TIMESTAMP_INDEX, timestamp_bucket, bounty_id);
         env.storage().persistent().remove(&timestamp_key);
 
+        remove_from_deadline_index(env, bounty_id, bounty.deadline);
+
         // Remove primary entry
         env.storage().persistent().remove(&key);
     }
 }
 
+/// Moves a bounty's index entries from `old_id` to `new_id`
+pub fn reindex_bounty(env: &Env, old_id: u64, new_id: u64) {
+    let key = (BOUNTY_INDEX, old_id);
+    if let Some(mut bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
+        remove_bounty(env, old_id);
+
+        bounty.bounty_id = new_id;
+        bounty.updated_at = env.ledger().timestamp();
+        index_bounty(env, bounty);
+    }
+}
+
+/// Extends the TTL of a bounty's entries in all indices
+pub fn extend_bounty_ttl(env: &Env, bounty_id: u64, threshold: u32, extend_to: u32) {
+    let key = (BOUNTY_INDEX, bounty_id);
+    if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
+        let storage = env.storage().persistent();
+        storage.extend_ttl(&key, threshold, extend_to);
+
+        let status_key = (STATUS_INDEX, bounty.status.clone(), bounty_id);
+        if storage.has(&status_key) {
+            storage.extend_ttl(&status_key, threshold, extend_to);
+        }
+
+        let depositor_key = (DEPOSITOR_INDEX, bounty.depositor.clone(), bounty_id);
+        if storage.has(&depositor_key) {
+            storage.extend_ttl(&depositor_key, threshold, extend_to);
+        }
+
+        let amount_bucket = (bounty.amount / 1_000_000_000) as u64;
+        let amount_key = (AMOUNT_INDEX, amount_bucket, bounty_id);
+        if storage.has(&amount_key) {
+            storage.extend_ttl(&amount_key, threshold, extend_to);
+        }
+
+        let timestamp_bucket = bounty.created_at / 86400;
+        let timestamp_key = (TIMESTAMP_INDEX, timestamp_bucket, bounty_id);
+        if storage.has(&timestamp_key) {
+            storage.extend_ttl(&timestamp_key, threshold, extend_to);
+        }
+
+        let deadline_key = (DEADLINE_INDEX, bounty.deadline / 86400);
+        if storage.has(&deadline_key) {
+            storage.extend_ttl(&deadline_key, threshold, extend_to);
+        }
+        if storage.has(&DEADLINE_DAYS) {
+            storage.extend_ttl(&DEADLINE_DAYS, threshold, extend_to);
+        }
+    }
+}
+
 /// Checks if a bounty exists
 pub fn bounty_exists(env: &Env, bounty_id: u64) -> bool {
     let key = (BOUNTY_INDEX, bounty_id);
@@ -192,6 +391,118 @@ pub fn get_depositor_bounty_ids(env: &Env, depositor: &Address) -> Vec<u64> {
     bounty_ids
 }
 
+/// Whether a bounty in `status` still holds funds
+fn is_open(status: &BountyStatus) -> bool {
+    !matches!(status, BountyStatus::Released | BountyStatus::Refunded)
+}
+
+/// Inserts a bounty into its deadline day bucket, keeping each bucket sorted
+/// by `(deadline, bounty_id)` and the list of non-empty days sorted
+fn add_to_deadline_index(env: &Env, bounty_id: u64, deadline: u64) {
+    let storage = env.storage().persistent();
+    let day = deadline / 86400;
+    let key = (DEADLINE_INDEX, day);
+    let mut entries: Vec<(u64, u64)> = storage.get(&key).unwrap_or(Vec::new(env));
+
+    let entry = (deadline, bounty_id);
+    let mut pos = entries.len();
+    for (i, existing) in entries.iter().enumerate() {
+        if existing == entry {
+            return;
+        }
+        if existing > entry {
+            pos = i as u32;
+            break;
+        }
+    }
+    entries.insert(pos, entry);
+    storage.set(&key, &entries);
+
+    if entries.len() == 1 {
+        let mut days: Vec<u64> = storage.get(&DEADLINE_DAYS).unwrap_or(Vec::new(env));
+        let mut pos = days.len();
+        for (i, existing) in days.iter().enumerate() {
+            if existing > day {
+                pos = i as u32;
+                break;
+            }
+        }
+        days.insert(pos, day);
+        storage.set(&DEADLINE_DAYS, &days);
+    }
+}
+
+/// Removes a bounty from its deadline day bucket, dropping empty buckets
+fn remove_from_deadline_index(env: &Env, bounty_id: u64, deadline: u64) {
+    let storage = env.storage().persistent();
+    let day = deadline / 86400;
+    let key = (DEADLINE_INDEX, day);
+    let mut entries: Vec<(u64, u64)> = match storage.get(&key) {
+        Some(entries) => entries,
+        None => return,
+    };
+
+    let Some(pos) = entries.first_index_of((deadline, bounty_id)) else {
+        return;
+    };
+    entries.remove(pos);
+
+    if !entries.is_empty() {
+        storage.set(&key, &entries);
+        return;
+    }
+
+    storage.remove(&key);
+    let mut days: Vec<u64> = storage.get(&DEADLINE_DAYS).unwrap_or(Vec::new(env));
+    if let Some(pos) = days.first_index_of(day) {
+        days.remove(pos);
+        storage.set(&DEADLINE_DAYS, &days);
+    }
+}
+
+/// Gets up to `limit` open bounty IDs whose deadline is at or before
+/// `timestamp`, earliest deadline first. Reads only the day buckets that
+/// can contain a match.
+pub fn get_bounties_due_before(env: &Env, timestamp: u64, limit: u32) -> Vec<u64> {
+    let storage = env.storage().persistent();
+    let mut bounty_ids = Vec::new(env);
+    if limit == 0 {
+        return bounty_ids;
+    }
+
+    let days: Vec<u64> = storage.get(&DEADLINE_DAYS).unwrap_or(Vec::new(env));
+    for day in days.iter() {
+        if day > timestamp / 86400 {
+            break;
+        }
+        let entries: Vec<(u64, u64)> = storage.get(&(DEADLINE_INDEX, day)).unwrap_or(Vec::new(env));
+        for (deadline, bounty_id) in entries.iter() {
+            if deadline > timestamp {
+                return bounty_ids;
+            }
+            bounty_ids.push_back(bounty_id);
+            if bounty_ids.len() == limit {
+                return bounty_ids;
+            }
+        }
+    }
+
+    bounty_ids
+}
+
+/// Adds `delta` to the maintained count of bounties in `status`
+fn adjust_status_count(env: &Env, status: &BountyStatus, delta: i32) {
+    let key = (STATUS_COUNT, status.clone());
+    let count = count_by_status(env, status).saturating_add_signed(delta);
+    env.storage().persistent().set(&key, &count);
+}
+
+/// Gets the number of bounties currently in a status, without scanning
+pub fn count_by_status(env: &Env, status: &BountyStatus) -> u32 {
+    let key = (STATUS_COUNT, status.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
 /// Gets all bounty IDs with a specific status
 pub fn get_status_bounty_ids(env: &Env, status: &BountyStatus) -> Vec<u64> {
     let mut bounty_ids = Vec::new(env);
@@ -205,3 +516,50 @@ pub fn get_status_bounty_ids(env: &Env, status: &BountyStatus) -> Vec<u64> {
 
     bounty_ids
 }
+
+/// Records a payout to a contributor in the contributor index and stats.
+/// A bounty is listed once per contributor even if paid out in several parts.
+pub fn index_contributor_payout(env: &Env, contributor: &Address, bounty_id: u64, amount: i128) {
+    let ids_key = (CONTRIBUTOR_INDEX, contributor.clone());
+    let mut bounty_ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&ids_key)
+        .unwrap_or(Vec::new(env));
+    let is_new_bounty = !bounty_ids.contains(bounty_id);
+    if is_new_bounty {
+        bounty_ids.push_back(bounty_id);
+        env.storage().persistent().set(&ids_key, &bounty_ids);
+    }
+
+    let mut stats = get_contributor_stats(env, contributor);
+    if is_new_bounty {
+        stats.total_bounties += 1;
+    }
+    stats.total_payouts += 1;
+    stats.total_received_value = stats.total_received_value.saturating_add(amount);
+    let stats_key = (CONTRIBUTOR_STATS, contributor.clone());
+    env.storage().persistent().set(&stats_key, &stats);
+}
+
+/// Gets all bounty IDs that paid out to a specific contributor
+pub fn get_contributor_bounty_ids(env: &Env, contributor: &Address) -> Vec<u64> {
+    let key = (CONTRIBUTOR_INDEX, contributor.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Gets the aggregated payout stats for a specific contributor
+pub fn get_contributor_stats(env: &Env, contributor: &Address) -> ContributorStats {
+    let key = (CONTRIBUTOR_STATS, contributor.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(ContributorStats {
+            total_bounties: 0,
+            total_payouts: 0,
+            total_received_value: 0,
+        })
+}
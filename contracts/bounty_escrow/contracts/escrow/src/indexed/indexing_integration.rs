@@ -1,14 +1,33 @@
+use crate::events::{
+    _emit_funds_locked, _emit_funds_refunded, _emit_funds_released, get_event_verbosity,
+    EventVerbosity, FundsLocked, FundsRefunded, FundsReleased,
+};
 use crate::indexed::enhanced_events::{
     _emit_bounty_activity, _emit_bounty_status_changed, _emit_enhanced_funds_locked,
-    _emit_enhanced_funds_refunded, _emit_enhanced_funds_released, create_event_metadata,
-    ActivityType, BountyActivity, BountyStatusChanged, EnhancedFundsLocked, EnhancedFundsRefunded,
-    EnhancedFundsReleased,
+    _emit_enhanced_funds_refunded, _emit_enhanced_funds_released, _emit_error_occurred,
+    create_event_metadata, ActivityType, BountyActivity, BountyStatusChanged, EnhancedFundsLocked,
+    EnhancedFundsRefunded, EnhancedFundsReleased, ErrorOccurred, ErrorType,
 };
 use crate::indexed::indexed_storage::{
-    index_bounty, update_bounty_status, BountyStatus, IndexedBounty,
+    get_bounty, index_bounty, index_contributor_payout, reindex_bounty, remove_bounty,
+    update_bounty_amount, update_bounty_deadline, update_bounty_depositor, update_bounty_status,
+    BountyStatus, IndexedBounty,
 };
-use crate::RefundMode;
-use soroban_sdk::{Address, Env};
+use crate::{DataKey, Error, Escrow, EscrowStatus, RefundMode};
+use soroban_sdk::{Address, Env, String};
+
+/// Stable name of an escrow status, used for every status string in events
+/// so indexers see a single vocabulary matching `EscrowStatus`.
+pub fn status_to_string(env: &Env, status: &EscrowStatus) -> String {
+    let name = match status {
+        EscrowStatus::Locked => "Locked",
+        EscrowStatus::Released => "Released",
+        EscrowStatus::Refunded => "Refunded",
+        EscrowStatus::PartiallyRefunded => "PartiallyRefunded",
+        EscrowStatus::PartiallyReleased => "PartiallyReleased",
+    };
+    String::from_str(env, name)
+}
 
 /// Called when funds are locked in escrow
 pub fn on_funds_locked(
@@ -32,6 +51,20 @@ pub fn on_funds_locked(
     };
     index_bounty(env, indexed_bounty);
 
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        _emit_funds_locked(
+            env,
+            FundsLocked {
+                bounty_id,
+                amount,
+                depositor: depositor.clone(),
+                deadline,
+            },
+        );
+        return;
+    }
+
     // Create event metadata
     let metadata = create_event_metadata(env);
 
@@ -46,6 +79,10 @@ pub fn on_funds_locked(
     };
     _emit_enhanced_funds_locked(env, enhanced_event);
 
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
     // _emit activity tracking event
     let activity = BountyActivity {
         bounty_id,
@@ -77,6 +114,23 @@ pub fn on_funds_released(
     };
     update_bounty_status(env, bounty_id, new_status.clone());
 
+    // Track the payout for contributor-centric queries
+    index_contributor_payout(env, recipient, bounty_id, amount);
+
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        _emit_funds_released(
+            env,
+            FundsReleased {
+                bounty_id,
+                amount,
+                recipient: recipient.clone(),
+                timestamp,
+            },
+        );
+        return;
+    }
+
     // Create event metadata
     let metadata = create_event_metadata(env);
 
@@ -92,18 +146,21 @@ pub fn on_funds_released(
     };
     _emit_enhanced_funds_released(env, enhanced_event);
 
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
     // _emit status change event
-    let old_status_str = if is_partial { "Locked" } else { "Locked" };
-    let new_status_str = if is_partial {
-        "PartiallyReleased"
+    let new_status = if is_partial {
+        EscrowStatus::PartiallyReleased
     } else {
-        "Released"
+        EscrowStatus::Released
     };
 
     let status_event = BountyStatusChanged {
         bounty_id,
-        old_status: soroban_sdk::String::from_str(env, old_status_str),
-        new_status: soroban_sdk::String::from_str(env, new_status_str),
+        old_status: status_to_string(env, &EscrowStatus::Locked),
+        new_status: status_to_string(env, &new_status),
         changed_by: recipient.clone(),
         timestamp,
         metadata: metadata.clone(),
@@ -148,6 +205,22 @@ pub fn on_funds_refunded(
     };
     update_bounty_status(env, bounty_id, new_status);
 
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        _emit_funds_refunded(
+            env,
+            FundsRefunded {
+                bounty_id,
+                amount,
+                refund_to: refund_to.clone(),
+                timestamp,
+                refund_mode,
+                remaining_amount,
+            },
+        );
+        return;
+    }
+
     // Create event metadata
     let metadata = create_event_metadata(env);
 
@@ -164,17 +237,21 @@ pub fn on_funds_refunded(
     };
     _emit_enhanced_funds_refunded(env, enhanced_event);
 
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
     // _emit status change event
-    let new_status_str = if remaining_amount > 0 {
-        "PartiallyRefunded"
+    let new_status = if remaining_amount > 0 {
+        EscrowStatus::PartiallyRefunded
     } else {
-        "Refunded"
+        EscrowStatus::Refunded
     };
 
     let status_event = BountyStatusChanged {
         bounty_id,
-        old_status: soroban_sdk::String::from_str(env, "Locked"),
-        new_status: soroban_sdk::String::from_str(env, new_status_str),
+        old_status: status_to_string(env, &EscrowStatus::Locked),
+        new_status: status_to_string(env, &new_status),
         changed_by: triggered_by.clone(),
         timestamp,
         metadata: metadata.clone(),
@@ -206,14 +283,19 @@ pub fn _on_bounty_cancelled(env: &Env, bounty_id: u64, cancelled_by: &Address) {
     // Update bounty status to refunded
     update_bounty_status(env, bounty_id, BountyStatus::Refunded);
 
+    if get_event_verbosity(env) != EventVerbosity::Full {
+        return;
+    }
+
     // Create event metadata
     let metadata = create_event_metadata(env);
 
     // _emit status change event
     let status_event = BountyStatusChanged {
         bounty_id,
-        old_status: soroban_sdk::String::from_str(env, "Locked"),
-        new_status: soroban_sdk::String::from_str(env, "Cancelled"),
+        old_status: status_to_string(env, &EscrowStatus::Locked),
+        // A cancelled bounty's funds are refunded, so it is reported as such
+        new_status: status_to_string(env, &EscrowStatus::Refunded),
         changed_by: cancelled_by.clone(),
         timestamp,
         metadata: metadata.clone(),
@@ -242,6 +324,14 @@ pub fn _on_deadline_extended(
 ) {
     use crate::indexed::enhanced_events::{BountyDeadlineExtended, _emit_bounty_deadline_extended};
 
+    // Keep the deadline index in order
+    update_bounty_deadline(env, bounty_id, new_deadline);
+
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        return;
+    }
+
     let timestamp = env.ledger().timestamp();
     let metadata = create_event_metadata(env);
 
@@ -256,6 +346,10 @@ pub fn _on_deadline_extended(
     };
     _emit_bounty_deadline_extended(env, event);
 
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
     // _emit activity tracking event
     let activity = BountyActivity {
         bounty_id,
@@ -278,9 +372,18 @@ pub fn _on_amount_increased(
 ) {
     use crate::indexed::enhanced_events::{BountyAmountIncreased, _emit_bounty_amount_increased};
 
+    let new_amount = old_amount + increase_amount;
+
+    // Keep the amount index in sync with the escrow
+    update_bounty_amount(env, bounty_id, new_amount);
+
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        return;
+    }
+
     let timestamp = env.ledger().timestamp();
     let metadata = create_event_metadata(env);
-    let new_amount = old_amount + increase_amount;
 
     // _emit amount increased event
     let event = BountyAmountIncreased {
@@ -294,6 +397,10 @@ pub fn _on_amount_increased(
     };
     _emit_bounty_amount_increased(env, event);
 
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
     // _emit activity tracking event
     let activity = BountyActivity {
         bounty_id,
@@ -305,3 +412,182 @@ pub fn _on_amount_increased(
     };
     _emit_bounty_activity(env, activity);
 }
+
+/// Called when a locked bounty is moved to a new id
+pub fn on_bounty_reassigned(env: &Env, old_id: u64, new_id: u64, reassigned_by: &Address) {
+    use crate::indexed::enhanced_events::{_emit_bounty_reassigned, BountyReassigned};
+
+    // Keep the indices keyed by the new id
+    reindex_bounty(env, old_id, new_id);
+
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        return;
+    }
+
+    let timestamp = env.ledger().timestamp();
+    let metadata = create_event_metadata(env);
+
+    // _emit reassignment event
+    let event = BountyReassigned {
+        old_bounty_id: old_id,
+        new_bounty_id: new_id,
+        reassigned_by: reassigned_by.clone(),
+        timestamp,
+        metadata: metadata.clone(),
+    };
+    _emit_bounty_reassigned(env, event);
+
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
+    // _emit activity tracking event
+    let activity = BountyActivity {
+        bounty_id: new_id,
+        activity_type: ActivityType::Reassigned,
+        actor: reassigned_by.clone(),
+        amount: None,
+        timestamp,
+        metadata,
+    };
+    _emit_bounty_activity(env, activity);
+}
+
+/// Called when a bounty's refund claim moves to a new depositor
+pub fn on_depositor_transferred(
+    env: &Env,
+    bounty_id: u64,
+    old_depositor: &Address,
+    new_depositor: &Address,
+) {
+    use crate::indexed::enhanced_events::{_emit_depositor_transferred, DepositorTransferred};
+
+    update_bounty_depositor(env, bounty_id, new_depositor);
+
+    let verbosity = get_event_verbosity(env);
+    if verbosity == EventVerbosity::Minimal {
+        return;
+    }
+
+    let timestamp = env.ledger().timestamp();
+    let metadata = create_event_metadata(env);
+
+    // _emit depositor transfer event
+    let event = DepositorTransferred {
+        bounty_id,
+        old_depositor: old_depositor.clone(),
+        new_depositor: new_depositor.clone(),
+        timestamp,
+        metadata: metadata.clone(),
+    };
+    _emit_depositor_transferred(env, event);
+
+    if verbosity != EventVerbosity::Full {
+        return;
+    }
+
+    // _emit activity tracking event
+    let activity = BountyActivity {
+        bounty_id,
+        activity_type: ActivityType::DepositorTransferred,
+        actor: old_depositor.clone(),
+        amount: None,
+        timestamp,
+        metadata,
+    };
+    _emit_bounty_activity(env, activity);
+}
+
+/// Called when a bounty is frozen or unfrozen, so the index reports
+/// `Frozen` while the freeze lasts and the escrow's own status afterwards
+pub fn on_bounty_freeze_changed(env: &Env, bounty_id: u64, escrow: &Escrow) {
+    update_bounty_status(env, bounty_id, index_status(env, bounty_id, escrow));
+}
+
+/// Index status for an escrow, using the same mapping as the lifecycle
+/// hooks. A frozen escrow that still holds funds reports `Frozen`.
+fn index_status(env: &Env, bounty_id: u64, escrow: &Escrow) -> BountyStatus {
+    let frozen = env
+        .storage()
+        .persistent()
+        .get::<_, bool>(&DataKey::FrozenBounty(bounty_id))
+        .unwrap_or(false);
+
+    match escrow.status {
+        EscrowStatus::Released => BountyStatus::Released,
+        EscrowStatus::Refunded => BountyStatus::Refunded,
+        _ if frozen => BountyStatus::Frozen,
+        EscrowStatus::Locked => BountyStatus::Locked,
+        EscrowStatus::PartiallyRefunded | EscrowStatus::PartiallyReleased => {
+            BountyStatus::PartiallyReleased
+        }
+    }
+}
+
+/// Rebuilds a bounty's index entries from its primary escrow record.
+/// Used to backfill escrows locked before indexing existed, or whose index
+/// entries expired while the escrow survived.
+pub fn rebuild_bounty_index(env: &Env, bounty_id: u64, escrow: &Escrow) {
+    let timestamp = env.ledger().timestamp();
+    let created_at = get_bounty(env, bounty_id)
+        .map(|bounty| bounty.created_at)
+        .unwrap_or(timestamp);
+
+    let status = index_status(env, bounty_id, escrow);
+
+    remove_bounty(env, bounty_id);
+    index_bounty(
+        env,
+        IndexedBounty {
+            bounty_id,
+            depositor: escrow.depositor.clone(),
+            amount: escrow.amount,
+            deadline: escrow.deadline,
+            status,
+            created_at,
+            updated_at: timestamp,
+        },
+    );
+}
+
+/// Maps a contract error to the indexed `ErrorType` and a short message,
+/// if it has one
+pub fn error_type_for(error: &Error) -> Option<(ErrorType, &'static str)> {
+    match error {
+        Error::BountyNotFound => Some((ErrorType::BountyNotFound, "bounty not found")),
+        Error::FundsNotLocked => Some((ErrorType::InvalidStatus, "funds not locked")),
+        Error::BountyFrozen => Some((ErrorType::InvalidStatus, "bounty frozen")),
+        Error::NotYetActive => Some((ErrorType::InvalidStatus, "not yet active")),
+        Error::DeadlineNotPassed => Some((ErrorType::DeadlineNotPassed, "deadline not passed")),
+        Error::InvalidDeadline => Some((ErrorType::InvalidDeadline, "invalid deadline")),
+        Error::InsufficientFunds => Some((ErrorType::InsufficientBalance, "insufficient funds")),
+        Error::TransferFailed => Some((ErrorType::InsufficientBalance, "transfer failed")),
+        Error::Unauthorized => Some((ErrorType::UnauthorizedAccess, "unauthorized")),
+        _ => None,
+    }
+}
+
+/// Called when an item of a non-reverting operation is rejected.
+/// Only call this from paths that go on to return `Ok`: a reverted
+/// invocation discards its events, so the event would never be published.
+pub fn on_operation_failed(env: &Env, error: &Error, bounty_id: Option<u64>, actor: &Address) {
+    if get_event_verbosity(env) == EventVerbosity::Minimal {
+        return;
+    }
+
+    let (error_type, message) = match error_type_for(error) {
+        Some(mapped) => mapped,
+        None => return,
+    };
+
+    let event = ErrorOccurred {
+        error_type,
+        bounty_id,
+        actor: actor.clone(),
+        message: String::from_str(env, message),
+        timestamp: env.ledger().timestamp(),
+        metadata: create_event_metadata(env),
+    };
+    _emit_error_occurred(env, event);
+}
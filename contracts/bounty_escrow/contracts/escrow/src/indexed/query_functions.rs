@@ -157,6 +157,18 @@ fn matches_filter(bounty: &IndexedBounty, filter: &QueryFilter) -> bool {
         }
     }
 
+    if let Some(deadline_from) = filter.deadline_from {
+        if bounty.deadline < deadline_from {
+            return false;
+        }
+    }
+
+    if let Some(deadline_to) = filter.deadline_to {
+        if bounty.deadline > deadline_to {
+            return false;
+        }
+    }
+
     true
 }
 
@@ -165,50 +177,38 @@ fn matches_filter(bounty: &IndexedBounty, filter: &QueryFilter) -> bool {
 // ============================================================================
 
 pub fn get_bounties_by_status(env: &Env, status: BountyStatus) -> Vec<IndexedBounty> {
-    let filter = QueryFilter {
-        status: status,
-        depositor: None,
-        min_amount: None,
-        max_amount: None,
-        from_timestamp: None,
-        to_timestamp: None,
-    };
+    let filter = QueryFilter::builder().status(status).build();
     get_filtered_bounties(env, &filter)
 }
 
 pub fn get_bounties_by_depositor(env: &Env, depositor: Address) -> Vec<IndexedBounty> {
-    let filter = QueryFilter {
-        status: BountyStatus::None,
-        depositor: Some(depositor),
-        min_amount: None,
-        max_amount: None,
-        from_timestamp: None,
-        to_timestamp: None,
-    };
+    let filter = QueryFilter::builder().depositor(depositor).build();
     get_filtered_bounties(env, &filter)
 }
 
 pub fn get_bounties_by_amount_range(env: &Env, min: i128, max: i128) -> Vec<IndexedBounty> {
-    let filter = QueryFilter {
-        status: BountyStatus::None,
-        depositor: None,
-        min_amount: Some(min),
-        max_amount: Some(max),
-        from_timestamp: None,
-        to_timestamp: None,
-    };
+    let filter = QueryFilter::builder()
+        .min_amount(min)
+        .max_amount(max)
+        .build();
     get_filtered_bounties(env, &filter)
 }
 
 pub fn get_bounties_by_date_range(env: &Env, from: u64, to: u64) -> Vec<IndexedBounty> {
-    let filter = QueryFilter {
-        status: BountyStatus::None,
-        depositor: None,
-        min_amount: None,
-        max_amount: None,
-        from_timestamp: Some(from),
-        to_timestamp: Some(to),
-    };
+    let filter = QueryFilter::builder()
+        .created_from(from)
+        .created_to(to)
+        .build();
+    get_filtered_bounties(env, &filter)
+}
+
+/// Locked bounties whose deadline falls within `[from, to]`, for refund keepers
+pub fn get_escrows_expiring_between(env: &Env, from: u64, to: u64) -> Vec<IndexedBounty> {
+    let filter = QueryFilter::builder()
+        .status(BountyStatus::Locked)
+        .deadline_from(from)
+        .deadline_to(to)
+        .build();
     get_filtered_bounties(env, &filter)
 }
 
@@ -232,14 +232,7 @@ pub fn get_recent_bounties(env: &Env, count: u32) -> Vec<IndexedBounty> {
 }
 
 pub fn get_total_locked_amount(env: &Env) -> i128 {
-    let filter = QueryFilter {
-        status: BountyStatus::Locked,
-        depositor: None,
-        min_amount: None,
-        max_amount: None,
-        from_timestamp: None,
-        to_timestamp: None,
-    };
+    let filter = QueryFilter::builder().status(BountyStatus::Locked).build();
 
     let bounties = get_filtered_bounties(env, &filter);
     let mut total = 0i128;
@@ -283,7 +276,7 @@ pub fn get_bounty_stats(env: &Env) -> BountyStats {
         let key = (BOUNTY_INDEX, bounty_id);
         if let Some(bounty) = env.storage().persistent().get::<_, IndexedBounty>(&key) {
             match bounty.status {
-                BountyStatus::Locked => {
+                BountyStatus::Locked | BountyStatus::Frozen => {
                     locked_count += 1;
                     total_locked += bounty.amount;
                 }
@@ -330,7 +323,7 @@ pub fn get_depositor_stats(env: &Env, depositor: &Address) -> DepositorStats {
             total_bounties += 1;
 
             match bounty.status {
-                BountyStatus::Locked => {
+                BountyStatus::Locked | BountyStatus::Frozen => {
                     active_bounties += 1;
                     total_locked_value += bounty.amount;
                 }
@@ -383,7 +376,9 @@ pub fn get_time_series_data(env: &Env, from: u64, to: u64, interval: u64) -> Vec
             if let Some(bounty) = bounties.get(i) {
                 count += 1;
                 match bounty.status {
-                    BountyStatus::Locked | BountyStatus::PartiallyReleased => {
+                    BountyStatus::Locked
+                    | BountyStatus::PartiallyReleased
+                    | BountyStatus::Frozen => {
                         locked_amount += bounty.amount;
                     }
                     BountyStatus::Released => {
@@ -97,12 +97,13 @@ use events::{
     ContractPaused, ContractUnpaused, EmergencyWithdrawal,
 };
 use indexed::{
-    _emit_bounty_initialized, on_funds_locked, on_funds_refunded, on_funds_released,
-    BountyEscrowInitialized,
+    _emit_bounty_initialized, _on_amount_increased, on_bounty_freeze_changed, on_bounty_reassigned,
+    on_depositor_transferred, on_funds_locked, on_funds_refunded, on_funds_released,
+    on_operation_failed, rebuild_bounty_index, BountyEscrowInitialized,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
 // ==================== MONITORING MODULE ====================
@@ -114,6 +115,8 @@ mod monitoring {
     #[allow(dead_code)]
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const LAST_OPERATION: &str = "last_op";
+    const PERIOD_SNAPSHOT: &str = "period_snap";
 
     // Event: Operation metric
     #[contracttype]
@@ -164,6 +167,20 @@ mod monitoring {
         pub total_errors: u64,
     }
 
+    // Data: Combined monitoring view for dashboards
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct MonitoringSnapshot {
+        pub timestamp: u64,
+        pub total_operations: u64,
+        pub error_count: u64,
+        pub error_rate: u32,
+        pub unique_users: u64,
+        pub is_healthy: bool,
+        pub contract_version: String,
+        pub last_operation: u64,
+    }
+
     // Data: Performance stats
     #[contracttype]
     #[derive(Clone, Debug)]
@@ -175,16 +192,47 @@ mod monitoring {
         pub last_called: u64,
     }
 
+    // Metric bookkeeping can be switched off by the admin to save gas
+    pub fn is_enabled(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&crate::DataKey::MonitoringEnabled)
+            .unwrap_or(true)
+    }
+
     // Track operation
     pub fn track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
+        if !is_enabled(env) {
+            return;
+        }
+
         let key = Symbol::new(env, OPERATION_COUNT);
         let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
         env.storage().persistent().set(&key, &(count + 1));
 
+        let last_key = Symbol::new(env, LAST_OPERATION);
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
+
+        let op_key = (Symbol::new(env, OPERATION_COUNT), operation.clone());
+        let op_count: u64 = env.storage().persistent().get(&op_key).unwrap_or(0);
+        env.storage().persistent().set(&op_key, &(op_count + 1));
+
         if !success {
             let err_key = Symbol::new(env, ERROR_COUNT);
             let err_count: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
             env.storage().persistent().set(&err_key, &(err_count + 1));
+
+            let op_err_key = (Symbol::new(env, ERROR_COUNT), operation.clone());
+            let op_err_count: u64 = env.storage().persistent().get(&op_err_key).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&op_err_key, &(op_err_count + 1));
+        }
+
+        if crate::events::get_event_verbosity(env) == crate::events::EventVerbosity::Minimal {
+            return;
         }
 
         env.events().publish(
@@ -200,6 +248,10 @@ mod monitoring {
 
     // Track performance
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
+        if !is_enabled(env) {
+            return;
+        }
+
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
         let time_key = (Symbol::new(env, "perf_time"), function.clone());
 
@@ -211,6 +263,10 @@ mod monitoring {
             .persistent()
             .set(&time_key, &(total + duration));
 
+        if crate::events::get_event_verbosity(env) == crate::events::EventVerbosity::Minimal {
+            return;
+        }
+
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
@@ -222,21 +278,20 @@ mod monitoring {
     }
 
     // Health check
-    #[allow(dead_code)]
     pub fn health_check(env: &Env) -> HealthStatus {
         let key = Symbol::new(env, OPERATION_COUNT);
         let ops: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        let last_key = Symbol::new(env, LAST_OPERATION);
 
         HealthStatus {
             is_healthy: true,
-            last_operation: env.ledger().timestamp(),
+            last_operation: env.storage().persistent().get(&last_key).unwrap_or(0),
             total_operations: ops,
             contract_version: String::from_str(env, "1.0.0"),
         }
     }
 
     // Get analytics
-    #[allow(dead_code)]
     pub fn get_analytics(env: &Env) -> Analytics {
         let op_key = Symbol::new(env, OPERATION_COUNT);
         let usr_key = Symbol::new(env, USER_COUNT);
@@ -260,8 +315,62 @@ mod monitoring {
         }
     }
 
+    // Get total and error counts for a single operation
+    pub fn get_operation_metrics(env: &Env, operation: Symbol) -> (u64, u64) {
+        let op_key = (Symbol::new(env, OPERATION_COUNT), operation.clone());
+        let err_key = (Symbol::new(env, ERROR_COUNT), operation);
+
+        (
+            env.storage().persistent().get(&op_key).unwrap_or(0),
+            env.storage().persistent().get(&err_key).unwrap_or(0),
+        )
+    }
+
+    // Get combined monitoring snapshot
+    pub fn get_monitoring_snapshot(env: &Env) -> MonitoringSnapshot {
+        let analytics = get_analytics(env);
+        let health = health_check(env);
+
+        MonitoringSnapshot {
+            timestamp: env.ledger().timestamp(),
+            total_operations: analytics.operation_count,
+            error_count: analytics.error_count,
+            error_rate: analytics.error_rate,
+            unique_users: analytics.unique_users,
+            is_healthy: health.is_healthy,
+            contract_version: health.contract_version,
+            last_operation: health.last_operation,
+        }
+    }
+
+    // Close the current reporting period: persist a snapshot and zero the live counters.
+    // Per-operation counters are cumulative and are not reset.
+    pub fn reset_period(env: &Env) -> StateSnapshot {
+        let snapshot = get_state_snapshot(env);
+
+        let snap_key = (Symbol::new(env, PERIOD_SNAPSHOT), snapshot.timestamp);
+        env.storage().persistent().set(&snap_key, &snapshot);
+
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, OPERATION_COUNT), &0u64);
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, USER_COUNT), &0u64);
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, ERROR_COUNT), &0u64);
+
+        snapshot
+    }
+
+    // Get a snapshot persisted by `reset_period`
+    pub fn get_period_snapshot(env: &Env, timestamp: u64) -> Option<StateSnapshot> {
+        let snap_key = (Symbol::new(env, PERIOD_SNAPSHOT), timestamp);
+        env.storage().persistent().get(&snap_key)
+    }
+
     // Get state snapshot
-    #[allow(dead_code)]
     pub fn get_state_snapshot(env: &Env) -> StateSnapshot {
         let op_key = Symbol::new(env, OPERATION_COUNT);
         let usr_key = Symbol::new(env, USER_COUNT);
@@ -301,7 +410,16 @@ mod monitoring {
 
 // ==================== ANTI-ABUSE MODULE ====================
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+    /// How violations are handled: `Enforce` rejects the operation, `Monitor`
+    /// only emits the violation event so limits can be tuned against real traffic.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum RateLimitMode {
+        Enforce,
+        Monitor,
+    }
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -309,6 +427,7 @@ mod anti_abuse {
         pub window_size: u64,     // Window size in seconds
         pub max_operations: u32,  // Max operations allowed in window
         pub cooldown_period: u64, // Minimum seconds between operations
+        pub mode: RateLimitMode,  // Enforce or only monitor violations
     }
 
     #[contracttype]
@@ -325,6 +444,7 @@ mod anti_abuse {
         Config,
         State(Address),
         Whitelist(Address),
+        WhitelistIndex, // All whitelisted addresses, kept in sync with Whitelist(_)
         Admin,
     }
 
@@ -336,10 +456,10 @@ mod anti_abuse {
                 window_size: 3600, // 1 hour default
                 max_operations: 10,
                 cooldown_period: 60, // 1 minute default
+                mode: RateLimitMode::Enforce,
             })
     }
 
-    #[allow(dead_code)]
     pub fn set_config(env: &Env, config: AntiAbuseConfig) {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
@@ -350,17 +470,41 @@ mod anti_abuse {
             .has(&AntiAbuseKey::Whitelist(address))
     }
 
-    #[allow(dead_code)]
     pub fn set_whitelist(env: &Env, address: Address, whitelisted: bool) {
+        let mut index = get_whitelisted(env);
+        let position = index.first_index_of(&address);
         if whitelisted {
             env.storage()
                 .instance()
-                .set(&AntiAbuseKey::Whitelist(address), &true);
+                .set(&AntiAbuseKey::Whitelist(address.clone()), &true);
+            if position.is_none() {
+                index.push_back(address);
+            }
         } else {
             env.storage()
                 .instance()
                 .remove(&AntiAbuseKey::Whitelist(address));
+            if let Some(position) = position {
+                index.remove(position);
+            }
         }
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::WhitelistIndex, &index);
+    }
+
+    /// Addresses currently whitelisted, in the order they were added.
+    pub fn get_whitelisted(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::WhitelistIndex)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// The contract admin and whitelisted addresses bypass rate limiting.
+    fn is_exempt(env: &Env, address: &Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&crate::DataKey::Admin);
+        admin.as_ref() == Some(address) || is_whitelisted(env, address.clone())
     }
 
     #[allow(dead_code)]
@@ -373,12 +517,53 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
+    /// Returns `(operation_count, cooldown_remaining, would_block)` for `address`
+    /// without mutating any state.
+    pub fn get_rate_limit_state(env: &Env, address: Address) -> (u32, u64, bool) {
+        let config = get_config(env);
+        let now = env.ledger().timestamp();
+        let state: Option<AddressState> = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::State(address.clone()));
+
+        let (operation_count, cooldown_remaining) = match state {
+            Some(state) => {
+                let window_end = state
+                    .window_start_timestamp
+                    .saturating_add(config.window_size);
+                let count = if now >= window_end {
+                    0
+                } else {
+                    state.operation_count
+                };
+                let cooldown_end = state
+                    .last_operation_timestamp
+                    .saturating_add(config.cooldown_period);
+                let remaining = if state.last_operation_timestamp > 0 && now < cooldown_end {
+                    cooldown_end - now
+                } else {
+                    0
+                };
+                (count, remaining)
+            }
+            None => (0, 0),
+        };
+
+        let would_block = config.mode == RateLimitMode::Enforce
+            && !is_exempt(env, &address)
+            && (cooldown_remaining > 0 || operation_count >= config.max_operations);
+
+        (operation_count, cooldown_remaining, would_block)
+    }
+
     pub fn check_rate_limit(env: &Env, address: Address) {
-        if is_whitelisted(env, address.clone()) {
+        if is_exempt(env, &address) {
             return;
         }
 
         let config = get_config(env);
+        let enforce = config.mode == RateLimitMode::Enforce;
         let now = env.ledger().timestamp();
         let key = AntiAbuseKey::State(address.clone());
 
@@ -403,7 +588,9 @@ mod anti_abuse {
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
-            panic!("Operation in cooldown period");
+            if enforce {
+                panic!("Operation in cooldown period");
+            }
         }
 
         // 2. Window check
@@ -422,9 +609,11 @@ mod anti_abuse {
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
-                panic!("Rate limit exceeded");
+                if enforce {
+                    panic!("Rate limit exceeded");
+                }
             }
-            state.operation_count += 1;
+            state.operation_count = state.operation_count.saturating_add(1);
         }
 
         state.last_operation_timestamp = now;
@@ -475,6 +664,41 @@ pub enum Error {
     /// Returned when refund is attempted without admin approval
     RefundNotApproved = 17,
     BatchSizeMismatch = 18,
+    /// Returned when the configured token address does not implement the token interface
+    UnsupportedToken = 19,
+    /// Returned when releasing or refunding a bounty that an admin has frozen
+    BountyFrozen = 20,
+    /// Returned when the fee recipient is the escrow contract itself
+    InvalidFeeRecipient = 21,
+    /// Returned when releasing or refunding before the escrow's `active_from` time
+    NotYetActive = 22,
+    /// Returned when pulling tokens from the depositor fails
+    /// (e.g. insufficient balance or missing authorization)
+    TransferFailed = 23,
+    /// Returned when locking funds after the contract has been sunset
+    LocksDisabled = 24,
+    /// Returned when a release lacks the required number of admin approvals
+    ReleaseNotApproved = 25,
+    /// Returned when the approval threshold is zero or exceeds the approver count
+    InvalidApprovalThreshold = 26,
+    /// Returned when a release targets an address the recipient policy rejects
+    InvalidRecipient = 27,
+    /// Returned when archiving an escrow that still holds funds
+    EscrowNotFinal = 28,
+    /// Returned when archiving an escrow before its retention period has elapsed
+    RetentionNotElapsed = 29,
+    /// Returned when a query filter has an inverted amount, timestamp or deadline range
+    InvalidQueryFilter = 30,
+    /// Returned when a bounty already has the maximum number of deadline subscribers
+    TooManySubscribers = 31,
+    /// Returned when refunding an escrow that has no remaining funds
+    NothingToRefund = 32,
+    /// Returned when querying a bounty that completed and was archived
+    BountyArchived = 33,
+    /// Returned when executing or cancelling a release that was never requested
+    ReleaseNotRequested = 34,
+    /// Returned when executing a release request before its timelock has passed
+    ReleaseTimelocked = 35,
 }
 
 // ============================================================================
@@ -486,6 +710,8 @@ pub enum Error {
 /// # State Transitions
 /// ```text
 /// NONE → Locked → Released (final)
+///           ↓  ↘
+///           ↓   PartiallyReleased → Released (final)
 ///           ↓
 ///        Refunded (final)
 /// ```
@@ -494,6 +720,8 @@ pub enum Error {
 /// * `Locked` - Funds are held in escrow, awaiting release or refund
 /// * `Released` - Funds have been transferred to contributor (final state)
 /// * `Refunded` - Funds have been returned to depositor (final state)
+/// * `PartiallyRefunded` - Part of the funds has been refunded, remainder still held
+/// * `PartiallyReleased` - Part of the funds has been released, remainder still held
 ///
 /// # Invariants
 /// - Once in Released or Refunded state, no further transitions allowed
@@ -505,6 +733,7 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    PartiallyReleased,
 }
 
 #[contracttype]
@@ -522,6 +751,7 @@ pub struct RefundRecord {
     pub recipient: Address,
     pub mode: RefundMode,
     pub timestamp: u64,
+    pub reason: Option<String>, // Why the refund happened (dispute, correction, cancellation...)
 }
 
 #[contracttype]
@@ -533,6 +763,30 @@ pub struct RefundApproval {
     pub mode: RefundMode,
     pub approved_by: Address,
     pub approved_at: u64,
+    pub reason: Option<String>,
+}
+
+/// One admin action in a `batch_admin_ops` call. Each variant takes the same
+/// arguments as the matching entry point.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminOp {
+    Release(u64, Address),                                         // release_funds
+    ApproveRefund(u64, i128, Address, RefundMode, Option<String>), // approve_refund
+    Freeze(u64),                                                   // freeze_bounty
+    Unfreeze(u64),                                                 // unfreeze_bounty
+}
+
+/// A pending timelocked release, created by `request_release` and consumed
+/// by `execute_release`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseRequest {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub requested_by: Address,
+    pub requested_at: u64,
+    pub executable_after: u64,
 }
 
 /// Complete escrow record for a bounty.
@@ -540,6 +794,10 @@ pub struct RefundApproval {
 /// # Fields
 /// * `depositor` - Address that locked the funds (receives refunds)
 /// * `amount` - Token amount held in escrow (in smallest denomination)
+/// * `gross_amount` - Amount the depositor paid in, before the lock fee
+/// * `lock_fee_paid` - Lock fee deducted at lock time (`gross_amount - amount`)
+/// * `updated_at` / `updated_seq` - Ledger timestamp and sequence of the last
+///   change; compare `updated_seq` between polls to detect updates
 /// * `status` - Current state of the escrow (Locked/Released/Refunded)
 /// * `deadline` - Unix timestamp after which refunds are allowed
 ///
@@ -547,13 +805,28 @@ pub struct RefundApproval {
 /// Stored in persistent storage with key `DataKey::Escrow(bounty_id)`.
 /// TTL is automatically extended on access.
 ///
+/// # Feature `fees`
+/// `gross_amount` and `lock_fee_paid` are present in every build on purpose.
+/// `#[contracttype]` cannot compile fields conditionally, and gating them
+/// would mean a second `Escrow` definition whose XDR differs by build: an
+/// escrow stored by one build could not be read after upgrading the contract
+/// to the other, and clients would need two schemas. Without `fees`,
+/// `gross_amount` equals `amount` and `lock_fee_paid` is always 0.
+///
 /// # Example
 /// ```rust
 /// let escrow = Escrow {
 ///     depositor: depositor_address,
 ///     amount: 1000_0000000, // 1000 tokens
+///     gross_amount: 1000_0000000,
+///     lock_fee_paid: 0,
+///     updated_at: current_time,
+///     updated_seq: env.ledger().sequence(),
 ///     status: EscrowStatus::Locked,
 ///     deadline: current_time + 2592000, // 30 days
+///     refund_history: vec![&env],
+///     remaining_amount: 1000_0000000,
+///     active_from: 0, // active immediately
 /// };
 /// ```
 #[contracttype]
@@ -565,6 +838,48 @@ pub struct Escrow {
     pub deadline: u64,
     pub refund_history: Vec<RefundRecord>,
     pub remaining_amount: i128,
+    pub active_from: u64, // Release/refund blocked before this timestamp (0 = no gate)
+    pub gross_amount: i128, // Deposited amount before the lock fee
+    pub lock_fee_paid: i128, // Lock fee deducted at lock time (always 0 without `fees`)
+    pub updated_at: u64,  // Ledger timestamp of the last change
+    pub updated_seq: u32, // Ledger sequence of the last change
+}
+
+/// Escrow state plus derived refund flags, for single-call detail views.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountySummary {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+    pub active_from: u64,
+    pub deadline_passed: bool, // Refund window (deadline + grace period) is open
+    pub has_valid_approval: bool, // A refund approval exists that still fits the escrow
+    pub is_refundable: bool,   // A refund could succeed right now
+}
+
+/// Core escrow fields without the refund history, for cheap client reads.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSummary {
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+}
+
+/// What a release of an escrow's remaining balance would pay out right now.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutBreakdown {
+    pub gross: i128,                    // Remaining amount that would be released
+    pub release_fee: i128,              // Fee deducted under the current fee config
+    pub net: i128,                      // Amount the contributor receives
+    pub fee_recipient: Option<Address>, // Where the fee goes; None when no fee is due
 }
 
 /// Metadata structure for enhanced escrow indexing and categorization.
@@ -666,9 +981,84 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
+/// Which release recipients are rejected with `InvalidRecipient`.
+/// Both flags are off by default, accepting any address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipientPolicy {
+    pub reject_self: bool,      // Reject the escrow contract's own address
+    pub reject_depositor: bool, // Reject the bounty's depositor
+}
+
+/// One entry in the admin configuration audit trail.
+/// `old` and `new` hold the setting's value before and after the change as
+/// XDR-encoded `ScVal`s, in the same type the corresponding getter returns.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigChange {
+    pub field: Symbol,
+    pub old: Bytes,
+    pub new: Bytes,
+    pub by: Address,
+    pub timestamp: u64,
+}
+
+/// Why a single item of a best-effort batch was skipped
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchItemError {
+    pub index: u32,      // Index in the original batch
+    pub bounty_id: u64,  // Bounty that was skipped
+    pub error_code: u32, // Error as u32
+}
+
+/// Outcome of `batch_release_best_effort`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchReleaseResult {
+    pub successful: Vec<u64>,        // Bounty ids released, in batch order
+    pub failed: Vec<BatchItemError>, // Items skipped and why
+    pub total_amount: i128,          // Net sum paid out across successful items
+}
+
 // Maximum batch size to prevent gas limit issues
 const MAX_BATCH_SIZE: u32 = 100;
 
+// Smallest amount accepted by lock operations
+const MIN_LOCK_AMOUNT: i128 = 1;
+
+// Default furthest a lock deadline may be set from now (no horizon is enforced)
+const MAX_DEADLINE_HORIZON: u64 = u64::MAX;
+
+/// Input limits enforced by the deployed contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Limits {
+    pub max_batch_size: u32,
+    pub min_lock_amount: i128,
+    pub max_fee_rate: i128,
+    pub max_deadline_horizon: u64,
+}
+
+/// Allowed distance between now and a lock deadline, in seconds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlinePolicy {
+    pub min_deadline_offset: u64, // Shortest allowed lock duration
+    pub max_deadline_offset: u64, // Longest allowed lock duration
+}
+
+/// How the fractional unit of a fee is resolved
+#[cfg(feature = "fees")]
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeRoundingMode {
+    Down,    // Truncate (depositor/contributor keeps the fraction)
+    Up,      // Round up (fee recipient gets the fraction)
+    Nearest, // Round half to even (banker's rounding)
+}
+
+#[cfg(feature = "fees")]
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
@@ -676,22 +1066,81 @@ pub struct FeeConfig {
     pub release_fee_rate: i128, // Fee rate for release operations (basis points)
     pub fee_recipient: Address, // Address to receive fees
     pub fee_enabled: bool,   // Global fee enable/disable flag
+    pub rounding_mode: FeeRoundingMode, // Rounding applied to fractional fees
 }
 
+// Escrow TTL management (in ledgers, ~5s each)
+const ESCROW_TTL_THRESHOLD: u32 = 518_400; // ~30 days
+const ESCROW_TTL_EXTEND_TO: u32 = 3_110_400; // ~180 days
+
+// Instance (configuration) TTL management, same horizon as escrows
+const INSTANCE_TTL_THRESHOLD: u32 = 518_400; // ~30 days
+const INSTANCE_TTL_EXTEND_TO: u32 = 3_110_400; // ~180 days
+
+// Refund records kept per escrow before further records are only emitted as events
+const DEFAULT_MAX_REFUND_HISTORY: u32 = 50;
+
+// Seconds a settled escrow is kept before it may be archived
+const DEFAULT_ARCHIVE_RETENTION: u64 = 30 * 24 * 60 * 60; // 30 days
+
+// Deadline alerts fire once the deadline is this close
+const DEADLINE_ALERT_WINDOW: u64 = 24 * 60 * 60; // 1 day
+
+// Subscribers kept per bounty, bounding the keeper's notification loop
+const MAX_DEADLINE_SUBSCRIBERS: u32 = 20;
+
 // Fee rate is stored in basis points (1 basis point = 0.01%)
 // Example: 100 basis points = 1%, 1000 basis points = 10%
+#[cfg(feature = "fees")]
 const BASIS_POINTS: i128 = 10_000;
+#[cfg(feature = "fees")]
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
+#[cfg(feature = "fees")]
+const MAX_COMBINED_FEE_RATE: i128 = MAX_FEE_RATE; // Lock + release fees together
+#[cfg(not(feature = "fees"))]
+const MAX_FEE_RATE: i128 = 0; // Built without fees
 
 #[contracttype]
 pub enum DataKey {
     Admin,
-    Token,
+    Token,               // Fixed at init; escrows don't record a token, so it must never change
     Escrow(u64),         // bounty_id
     FeeConfig,           // Fee configuration
     RefundApproval(u64), // bounty_id -> RefundApproval
     ReentrancyGuard,
-    IsPaused, // Contract pause state
+    IsPaused,                       // Contract pause state
+    FrozenBounty(u64),              // bounty_id -> frozen flag
+    RefundGracePeriod,              // Seconds after the deadline before refunds open
+    ClientRef(Address, BytesN<32>), // (depositor, client_ref) -> bounty_id
+    BountyClientRef(u64),           // bounty_id -> (depositor, client_ref), reverse of ClientRef
+    EventVerbosity,                 // events::EventVerbosity
+    LargeLockThreshold,             // Locks at or above this need admin co-signature
+    TotalLocked,                    // Sum of remaining_amount across all escrows
+    FeeAccrual,                     // Keep fees in the contract instead of transferring
+    AccruedFees,                    // Fees held by the contract awaiting withdrawal
+    LockFeeAccrued(u64),            // bounty_id -> its lock fee was accrued, not sent out
+    MaxRefundHistory,               // Cap on stored refund records per escrow
+    PausedSince,                    // Ledger timestamp of the current pause
+    RefundDestination(u64),         // bounty_id -> depositor-chosen refund address
+    Sunset,                         // Permanent flag: no new locks are accepted
+    ReleaseApprovers,               // Admins allowed to approve releases
+    ReleaseApprovalsRequired,       // Approvals needed before a release executes
+    ReleaseApprovals(u64),          // bounty_id -> admins that approved its release
+    RecipientPolicy,                // Release recipients rejected in strict mode
+    ConfigHistory(u32),             // index -> one entry of the admin configuration log
+    ConfigHistoryCount,             // Entries ever written to the configuration log
+    ArchiveRetention,               // Seconds a settled escrow is kept before archiving
+    EscrowCount,                    // Escrows ever locked
+    DepositorEscrowCount(Address),  // depositor -> escrows they hold
+    DeadlineSubscribers(u64),       // bounty_id -> addresses wanting deadline alerts
+    DeadlineAlertSent(u64),         // bounty_id -> deadline the last alerts were sent for
+    MonitoringEnabled,              // Operation and performance metrics are recorded
+    StateObserver,                  // Contract notified of escrow status transitions
+    DeadlinePolicy,                 // Allowed lock duration band
+    NextBountyId,                   // Next candidate id for lock_funds_auto_id
+    ArchivedBountyId(u64),          // bounty_id -> marker kept after archiving; id is never reused
+    ReleaseTimelock,                // Seconds between request_release and execute_release
+    ReleaseRequest(u64),            // bounty_id -> pending ReleaseRequest
 }
 
 // ============================================================================
@@ -713,14 +1162,21 @@ impl BountyEscrowContract {
     /// * `env` - The contract environment
     /// * `admin` - Address authorized to release funds
     /// * `token` - Token contract address for escrow payments (e.g., XLM, USDC)
+    /// * `fee_config` - Optional initial fee configuration; `None` starts with
+    ///   fees disabled and the admin as fee recipient
     ///
     /// # Returns
     /// * `Ok(())` - Contract successfully initialized
     /// * `Err(Error::AlreadyInitialized)` - Contract already initialized
+    /// * `Err(Error::UnsupportedToken)` - `token` is not a token contract
+    /// * `Err(Error::InvalidFeeRate)` - A fee rate is outside `0..=MAX_FEE_RATE`,
+    ///   or lock and release rates together exceed `MAX_COMBINED_FEE_RATE`
+    /// * `Err(Error::InvalidFeeRecipient)` - Fee recipient is this contract
     ///
     /// # State Changes
     /// - Sets Admin address in instance storage
     /// - Sets Token address in instance storage
+    /// - Sets FeeConfig in instance storage
     /// - Emits BountyEscrowInitialized event
     ///
     /// # Security Considerations
@@ -729,6 +1185,13 @@ impl BountyEscrowContract {
     /// - Token must be a valid Stellar Asset Contract
     /// - No authorization required (first-caller initialization)
     ///
+    /// # Native XLM
+    /// Soroban has no separate native-balance path: all transfers go through the
+    /// token interface. To escrow XLM, pass the address of the native asset's
+    /// Stellar Asset Contract (e.g. `stellar contract id asset --asset native`).
+    /// Any address that does not respond to the token interface is rejected with
+    /// `Error::UnsupportedToken`.
+    ///
     /// # Events
     /// Emits: `BountyEscrowInitialized { admin, token, timestamp }`
     ///
@@ -736,15 +1199,35 @@ impl BountyEscrowContract {
     /// ```rust
     /// let admin = Address::from_string("GADMIN...");
     /// let usdc_token = Address::from_string("CUSDC...");
-    /// escrow_client.init(&admin, &usdc_token)?;
+    /// escrow_client.init(&admin, &usdc_token, &None)?;
     /// ```
     ///
     /// # Gas Cost
     /// Low - Only two storage writes
+    #[cfg(feature = "fees")]
+    pub fn init(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fee_config: Option<FeeConfig>,
+    ) -> Result<(), Error> {
+        Self::init_internal(env, admin, token, fee_config)
+    }
+
+    /// Initialize the contract; same as `init` in a build without the `fees`
+    /// feature, which takes no fee configuration.
+    #[cfg(not(feature = "fees"))]
     pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+        Self::init_internal(env, admin, token)
+    }
 
+    /// Shared body of the `init` entry points (internal helper)
+    fn init_internal(
+        env: Env,
+        admin: Address,
+        token: Address,
+        #[cfg(feature = "fees")] fee_config: Option<FeeConfig>,
+    ) -> Result<(), Error> {
         let start = env.ledger().timestamp();
         let caller = admin.clone();
 
@@ -754,20 +1237,46 @@ impl BountyEscrowContract {
             return Err(Error::AlreadyInitialized);
         }
 
+        // Apply rate limiting only once re-initialization is ruled out, so
+        // failed init calls never touch anti-abuse state
+        anti_abuse::check_rate_limit(&env, admin.clone());
+
+        // Reject addresses that don't implement the token interface
+        let token_client = token::Client::new(&env, &token);
+        if !matches!(token_client.try_decimals(), Ok(Ok(_))) {
+            monitoring::track_operation(&env, symbol_short!("init"), caller, false);
+            return Err(Error::UnsupportedToken);
+        }
+
+        // Use the provided fee config, or zero fees (disabled by default)
+        #[cfg(feature = "fees")]
+        let fee_config = match fee_config {
+            Some(config) => {
+                if let Err(err) = Self::validate_fee_config(&env, &config) {
+                    monitoring::track_operation(&env, symbol_short!("init"), caller, false);
+                    return Err(err);
+                }
+                config
+            }
+            None => FeeConfig {
+                lock_fee_rate: 0,
+                release_fee_rate: 0,
+                fee_recipient: admin.clone(),
+                fee_enabled: false,
+                rounding_mode: FeeRoundingMode::Down,
+            },
+        };
+
         // Store configuration
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
-
-        // Initialize fee config with zero fees (disabled by default)
-        let fee_config = FeeConfig {
-            lock_fee_rate: 0,
-            release_fee_rate: 0,
-            fee_recipient: admin.clone(),
-            fee_enabled: false,
-        };
+        #[cfg(feature = "fees")]
         env.storage()
             .instance()
             .set(&DataKey::FeeConfig, &fee_config);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
 
         // Emit initialization event
         // emit_bounty_initialized(
@@ -799,20 +1308,118 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Initialize the contract with a set of admins that must co-approve releases.
+    ///
+    /// Behaves like `init`, then stores `approvers` and the
+    /// `release_approvals_required` threshold. From then on every release
+    /// (single or batch) needs that many `approve_release` calls from distinct
+    /// approvers before it executes; approvals are consumed by the release.
+    ///
+    /// # Errors
+    /// Same as `init`, plus:
+    /// * `InvalidApprovalThreshold` - threshold is zero or exceeds the number of approvers
+    #[cfg(feature = "fees")]
+    pub fn init_with_approvers(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fee_config: Option<FeeConfig>,
+        approvers: Vec<Address>,
+        release_approvals_required: u32,
+    ) -> Result<(), Error> {
+        Self::check_approval_threshold(&approvers, release_approvals_required)?;
+        Self::init_internal(env.clone(), admin, token, fee_config)?;
+        Self::store_release_approvers(&env, &approvers, release_approvals_required);
+        Ok(())
+    }
+
+    /// Initialize with release approvers; same as `init_with_approvers` in a
+    /// build without the `fees` feature, which takes no fee configuration.
+    #[cfg(not(feature = "fees"))]
+    pub fn init_with_approvers(
+        env: Env,
+        admin: Address,
+        token: Address,
+        approvers: Vec<Address>,
+        release_approvals_required: u32,
+    ) -> Result<(), Error> {
+        Self::check_approval_threshold(&approvers, release_approvals_required)?;
+        Self::init_internal(env.clone(), admin, token)?;
+        Self::store_release_approvers(&env, &approvers, release_approvals_required);
+        Ok(())
+    }
+
+    /// Threshold must be reachable by the given approvers (internal helper)
+    fn check_approval_threshold(approvers: &Vec<Address>, required: u32) -> Result<(), Error> {
+        if required == 0 || required > approvers.len() {
+            return Err(Error::InvalidApprovalThreshold);
+        }
+        Ok(())
+    }
+
+    /// Store release approvers and their threshold (internal helper)
+    fn store_release_approvers(env: &Env, approvers: &Vec<Address>, required: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::ReleaseApprovers, approvers);
+        env.storage()
+            .instance()
+            .set(&DataKey::ReleaseApprovalsRequired, &required);
+    }
+
+    /// Check fee rates are within bounds and fees don't flow back into escrow
+    #[cfg(feature = "fees")]
+    fn validate_fee_config(env: &Env, config: &FeeConfig) -> Result<(), Error> {
+        if !(0..=MAX_FEE_RATE).contains(&config.lock_fee_rate)
+            || !(0..=MAX_FEE_RATE).contains(&config.release_fee_rate)
+        {
+            return Err(Error::InvalidFeeRate);
+        }
+        if config.lock_fee_rate + config.release_fee_rate > MAX_COMBINED_FEE_RATE {
+            return Err(Error::InvalidFeeRate);
+        }
+        if config.fee_recipient == env.current_contract_address() {
+            return Err(Error::InvalidFeeRecipient);
+        }
+        Ok(())
+    }
+
     /// Calculate fee amount based on rate (in basis points)
-    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
+    #[cfg(feature = "fees")]
+    fn calculate_fee(amount: i128, fee_rate: i128, rounding: FeeRoundingMode) -> i128 {
         if fee_rate == 0 {
             return 0;
         }
         // Fee = (amount * fee_rate) / BASIS_POINTS
         // Using checked arithmetic to prevent overflow
-        amount
-            .checked_mul(fee_rate)
-            .and_then(|x| x.checked_div(BASIS_POINTS))
-            .unwrap_or(0)
+        let product = match amount.checked_mul(fee_rate) {
+            Some(p) => p,
+            None => return 0,
+        };
+        let quotient = product / BASIS_POINTS;
+        let remainder = product % BASIS_POINTS;
+        if remainder == 0 {
+            return quotient;
+        }
+
+        let round_up = match rounding {
+            FeeRoundingMode::Down => false,
+            FeeRoundingMode::Up => true,
+            FeeRoundingMode::Nearest => {
+                let twice = remainder * 2;
+                twice > BASIS_POINTS || (twice == BASIS_POINTS && quotient % 2 == 1)
+            }
+        };
+        // Never charge more than the amount itself
+        if round_up {
+            (quotient + 1).min(amount)
+        } else {
+            quotient
+        }
     }
 
     /// Get fee configuration (internal helper)
+    #[cfg(feature = "fees")]
     fn get_fee_config_internal(env: &Env) -> FeeConfig {
         env.storage()
             .instance()
@@ -822,178 +1429,461 @@ impl BountyEscrowContract {
                 release_fee_rate: 0,
                 fee_recipient: env.storage().instance().get(&DataKey::Admin).unwrap(),
                 fee_enabled: false,
+                rounding_mode: FeeRoundingMode::Down,
             })
     }
 
-    /// Update fee configuration (admin only)
-    pub fn update_fee_config(
-        env: Env,
-        lock_fee_rate: Option<i128>,
-        release_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
+    /// Pull tokens from a depositor, mapping a failed token transfer to
+    /// `Error::TransferFailed` instead of an opaque host error (internal helper)
+    fn transfer_in(
+        client: &token::Client,
+        from: &Address,
+        to: &Address,
+        amount: i128,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        match client.try_transfer(from, to, &amount) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(Error::TransferFailed),
         }
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Add `delta` to the outstanding escrow total (internal helper)
+    fn adjust_total_locked(env: &Env, delta: i128) {
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalLocked)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalLocked, &total.saturating_add(delta));
+    }
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
+    /// Get the sum of remaining amounts across all escrows (view function)
+    pub fn get_total_locked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalLocked)
+            .unwrap_or(0)
+    }
 
-        if let Some(rate) = lock_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
-            }
-            fee_config.lock_fee_rate = rate;
-        }
+    /// Bump the global and per-depositor escrow counters (internal helper)
+    fn count_new_escrow(env: &Env, depositor: &Address) {
+        let total = Self::get_escrow_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowCount, &(total + 1));
+        Self::adjust_depositor_escrow_count(env, depositor, 1);
+    }
 
-        if let Some(rate) = release_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
-            }
-            fee_config.release_fee_rate = rate;
-        }
+    /// Add `delta` to a depositor's escrow count (internal helper)
+    fn adjust_depositor_escrow_count(env: &Env, depositor: &Address, delta: i32) {
+        let count = Self::get_depositor_escrow_count(env.clone(), depositor.clone());
+        env.storage().persistent().set(
+            &DataKey::DepositorEscrowCount(depositor.clone()),
+            &count.saturating_add_signed(delta),
+        );
+    }
 
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
-        }
+    /// Get the number of escrows ever locked in this contract (view function)
+    pub fn get_escrow_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscrowCount)
+            .unwrap_or(0)
+    }
 
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
+    /// Get the number of escrows held by `depositor`, including settled
+    /// ones; `transfer_depositor` moves the count to the new owner
+    /// (view function)
+    pub fn get_depositor_escrow_count(env: Env, depositor: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DepositorEscrowCount(depositor))
+            .unwrap_or(0)
+    }
+
+    /// Get the sum of remaining amounts across escrows denominated in
+    /// `token` (view function).
+    ///
+    /// Every escrow in this contract uses the token set at `init`, so this is
+    /// `get_total_locked` for that token and zero for any other.
+    pub fn get_tvl_by_token(env: Env, token: Address) -> i128 {
+        let escrow_token: Option<Address> = env.storage().instance().get(&DataKey::Token);
+        if escrow_token != Some(token) {
+            return 0;
         }
+        Self::get_total_locked(env)
+    }
 
+    #[cfg(feature = "fees")]
+    fn is_fee_accrual_enabled(env: &Env) -> bool {
         env.storage()
             .instance()
-            .set(&DataKey::FeeConfig, &fee_config);
+            .get(&DataKey::FeeAccrual)
+            .unwrap_or(false)
+    }
 
-        events::emit_fee_config_updated(
-            &env,
-            events::FeeConfigUpdated {
-                lock_fee_rate: fee_config.lock_fee_rate,
-                release_fee_rate: fee_config.release_fee_rate,
-                fee_recipient: fee_config.fee_recipient.clone(),
-                fee_enabled: fee_config.fee_enabled,
+    #[cfg(feature = "fees")]
+    fn accrue_fee(env: &Env, amount: i128) {
+        let accrued = Self::get_accrued_fees(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::AccruedFees, &(accrued + amount));
+    }
+
+    /// Get operation, error and health metrics in a single call (view function)
+    ///
+    /// `error_rate` is in basis points; `last_operation` is the timestamp of
+    /// the most recently tracked operation (0 if none).
+    pub fn get_monitoring_snapshot(env: Env) -> monitoring::MonitoringSnapshot {
+        monitoring::get_monitoring_snapshot(&env)
+    }
+
+    /// Close the current monitoring period (admin only).
+    ///
+    /// Persists the current counters as a `StateSnapshot` keyed by the ledger
+    /// timestamp and zeroes the live operation, user and error counters.
+    ///
+    /// # Returns
+    /// The snapshot of the period that was just closed
+    pub fn reset_monitoring_period(env: Env) -> Result<monitoring::StateSnapshot, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        Ok(monitoring::reset_period(&env))
+    }
+
+    /// Get a monitoring period snapshot by the timestamp it was taken at (view function)
+    pub fn get_monitoring_period(env: Env, timestamp: u64) -> Option<monitoring::StateSnapshot> {
+        monitoring::get_period_snapshot(&env, timestamp)
+    }
+
+    /// Get `(total, errors)` tracked for one operation, e.g. `release` or `refund` (view function)
+    pub fn get_operation_metrics(env: Env, operation: Symbol) -> (u64, u64) {
+        monitoring::get_operation_metrics(&env, operation)
+    }
+
+    /// Get the input limits enforced by this deployment (view function)
+    ///
+    /// Lets clients validate batches, amounts and fee rates locally against
+    /// the deployed values instead of hard-coding them.
+    pub fn get_limits(env: Env) -> Limits {
+        Limits {
+            max_batch_size: MAX_BATCH_SIZE,
+            min_lock_amount: MIN_LOCK_AMOUNT,
+            max_fee_rate: MAX_FEE_RATE,
+            max_deadline_horizon: Self::get_deadline_policy(env).max_deadline_offset,
+        }
+    }
+
+    // ========================================================================
+    // Configuration History
+    // ========================================================================
+
+    /// Append a change to the configuration audit trail (internal helper)
+    fn record_config_change<T: IntoVal<Env, Val>>(
+        env: &Env,
+        field: Symbol,
+        old: T,
+        new: T,
+        by: &Address,
+    ) {
+        // One entry per key keeps each write constant-size however long the
+        // log gets, so setters (including `pause`) can't outgrow the entry limit
+        let index: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigHistoryCount)
+            .unwrap_or(0);
+        let key = DataKey::ConfigHistory(index);
+        env.storage().persistent().set(
+            &key,
+            &ConfigChange {
+                field,
+                old: old.to_xdr(env),
+                new: new.to_xdr(env),
+                by: by.clone(),
                 timestamp: env.ledger().timestamp(),
             },
         );
-
-        Ok(())
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, ESCROW_TTL_THRESHOLD, ESCROW_TTL_EXTEND_TO);
+        env.storage()
+            .instance()
+            .set(&DataKey::ConfigHistoryCount, &(index + 1));
     }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+    /// Retrieves one page of the admin configuration history, oldest first.
+    ///
+    /// Every admin setter (fees, rate limits, pause state, thresholds, ...)
+    /// appends one `ConfigChange`, stored under its own index. Entries whose
+    /// TTL has lapsed are skipped.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `page` - Zero-based page index
+    /// * `page_size` - Records per page
+    pub fn get_config_history(env: Env, page: u32, page_size: u32) -> Vec<ConfigChange> {
+        let total_count: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigHistoryCount)
+            .unwrap_or(0);
+
+        let start = page.saturating_mul(page_size).min(total_count);
+        let end = start.saturating_add(page_size).min(total_count);
+
+        let mut history = Vec::new(&env);
+        for index in start..end {
+            if let Some(change) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ConfigHistory(index))
+            {
+                history.push_back(change);
+            }
+        }
+        history
     }
 
     // ========================================================================
-    // Pause and Emergency Functions
+    // Event Verbosity
     // ========================================================================
 
-    /// Check if contract is paused (internal helper)
-    fn is_paused_internal(env: &Env) -> bool {
+    /// Set how many events lifecycle operations emit (admin only).
+    /// `Minimal` suppresses enhanced, activity and metric events to save gas on
+    /// deployments that don't use the indexing layer.
+    pub fn set_event_verbosity(env: Env, verbosity: events::EventVerbosity) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let old = events::get_event_verbosity(&env);
         env.storage()
-            .persistent()
-            .get::<_, bool>(&DataKey::IsPaused)
-            .unwrap_or(false)
+            .instance()
+            .set(&DataKey::EventVerbosity, &verbosity);
+        Self::record_config_change(&env, symbol_short!("verbosity"), old, verbosity, &admin);
+
+        Ok(())
     }
 
-    /// Get pause status (view function)
-    pub fn is_paused(env: Env) -> bool {
-        Self::is_paused_internal(&env)
+    /// Get the current event verbosity (view function)
+    pub fn get_event_verbosity(env: Env) -> events::EventVerbosity {
+        events::get_event_verbosity(&env)
     }
 
-    /// Pause the contract (admin only)
-    /// Prevents new fund locks, releases, and refunds
-    pub fn pause(env: Env) -> Result<(), Error> {
+    /// Turn operation and performance metrics on or off (admin only).
+    /// Disabling skips their storage writes and `metric` events; escrow
+    /// behaviour is unaffected. Enabled by default.
+    pub fn set_monitoring_enabled(env: Env, enabled: bool) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        if Self::is_paused_internal(&env) {
-            return Ok(()); // Already paused, idempotent
+        let old = monitoring::is_enabled(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::MonitoringEnabled, &enabled);
+        Self::record_config_change(&env, symbol_short!("monitor"), old, enabled, &admin);
+
+        Ok(())
+    }
+
+    /// Check whether operation and performance metrics are recorded (view function)
+    pub fn is_monitoring_enabled(env: Env) -> bool {
+        monitoring::is_enabled(&env)
+    }
+
+    /// Get the topic symbols this contract emits, so indexers can check they
+    /// understand the deployed event vocabulary (view function).
+    /// The list changes only together with the event metadata `version`.
+    pub fn get_event_topics(env: Env) -> Vec<Symbol> {
+        indexed::event_topics(&env)
+    }
+
+    // ========================================================================
+    // Recipient Policy
+    // ========================================================================
+
+    /// Set which release recipients are rejected (admin only).
+    /// Guards against integrator bugs that pass the escrow contract itself or
+    /// the depositor as the contributor.
+    pub fn set_recipient_policy(env: Env, policy: RecipientPolicy) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &true);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        emit_contract_paused(
-            &env,
-            ContractPaused {
-                paused_by: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+        let old = Self::get_recipient_policy(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientPolicy, &policy);
+        Self::record_config_change(&env, symbol_short!("rcpt_pol"), old, policy, &admin);
 
         Ok(())
     }
 
-    /// Unpause the contract (admin only)
-    /// Resumes normal operations
-    pub fn unpause(env: Env) -> Result<(), Error> {
+    /// Set the contract notified of escrow status transitions, or `None` to
+    /// stop notifying (admin only).
+    ///
+    /// On every status change the escrow calls
+    /// `notify_transition(bounty_id, old_status: Option<EscrowStatus>, new_status)`
+    /// on the observer, with `old_status` `None` for a newly locked escrow.
+    /// The call is best-effort: if the observer fails, its changes are
+    /// discarded and the escrow operation still succeeds.
+    pub fn set_state_observer(env: Env, observer: Option<Address>) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        if !Self::is_paused_internal(&env) {
-            return Ok(()); // Already unpaused, idempotent
+        let old = Self::get_state_observer(env.clone());
+        match &observer {
+            Some(observer) => env
+                .storage()
+                .instance()
+                .set(&DataKey::StateObserver, observer),
+            None => env.storage().instance().remove(&DataKey::StateObserver),
         }
+        Self::record_config_change(&env, symbol_short!("observer"), old, observer, &admin);
 
-        env.storage().persistent().set(&DataKey::IsPaused, &false);
+        Ok(())
+    }
 
-        emit_contract_unpaused(
-            &env,
-            ContractUnpaused {
-                unpaused_by: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+    /// Get the contract notified of escrow status transitions, if any (view function)
+    pub fn get_state_observer(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::StateObserver)
+    }
+
+    /// Get the current recipient policy (view function)
+    pub fn get_recipient_policy(env: Env) -> RecipientPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::RecipientPolicy)
+            .unwrap_or(RecipientPolicy {
+                reject_self: false,
+                reject_depositor: false,
+            })
+    }
 
+    /// Ensure `recipient` is allowed by the recipient policy (internal helper)
+    fn check_recipient(env: &Env, escrow: &Escrow, recipient: &Address) -> Result<(), Error> {
+        let policy = Self::get_recipient_policy(env.clone());
+        if policy.reject_self && *recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
+        }
+        if policy.reject_depositor && *recipient == escrow.depositor {
+            return Err(Error::InvalidRecipient);
+        }
         Ok(())
     }
 
-    /// Emergency withdrawal for all contract funds (admin only, only when paused)
-    /// This function allows admins to recover all contract funds in case of critical
-    /// security issues or unrecoverable bugs. It can only be called when the contract
-    /// is paused to prevent misuse.
-    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
+    // ========================================================================
+    // Large Lock Co-Signature
+    // ========================================================================
+
+    /// Require admin co-authorization for locks of at least `threshold` (admin only).
+    /// Pass `None` to disable; smaller locks only need the depositor's auth.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `InvalidAmount` - `threshold` is not positive
+    pub fn set_large_lock_threshold(env: Env, threshold: Option<i128>) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Only allow emergency withdrawal when contract is paused
-        if !Self::is_paused_internal(&env) {
-            return Err(Error::Unauthorized);
+        let old = Self::get_large_lock_threshold(env.clone());
+        match threshold {
+            Some(value) if value <= 0 => return Err(Error::InvalidAmount),
+            Some(value) => env
+                .storage()
+                .instance()
+                .set(&DataKey::LargeLockThreshold, &value),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::LargeLockThreshold),
         }
+        Self::record_config_change(&env, symbol_short!("lg_lock"), old, threshold, &admin);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        Ok(())
+    }
 
-        // Get contract balance
-        let balance = client.balance(&env.current_contract_address());
+    /// Get the large lock threshold, if any (view function)
+    pub fn get_large_lock_threshold(env: Env) -> Option<i128> {
+        env.storage().instance().get(&DataKey::LargeLockThreshold)
+    }
 
-        if balance <= 0 {
-            return Ok(()); // No funds to withdraw
+    fn requires_admin_cosign(env: &Env, amount: i128) -> bool {
+        match env
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey::LargeLockThreshold)
+        {
+            Some(threshold) => amount >= threshold,
+            None => false,
         }
+    }
 
-        // Transfer all funds to recipient
-        client.transfer(&env.current_contract_address(), &recipient, &balance);
+    // ========================================================================
+    // Refund Grace Period
+    // ========================================================================
 
-        emit_emergency_withdrawal(
+    /// Set the grace period (in seconds) after a bounty deadline before
+    /// refunds are allowed without approval (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `grace_period` - Seconds added to every escrow deadline; 0 disables it
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    pub fn set_refund_grace_period(env: Env, grace_period: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let old = Self::get_refund_grace_period(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundGracePeriod, &grace_period);
+        Self::record_config_change(&env, symbol_short!("grace"), old, grace_period, &admin);
+
+        events::emit_refund_grace_period_updated(
             &env,
-            EmergencyWithdrawal {
-                withdrawn_by: admin.clone(),
-                amount: balance,
-                recipient: recipient.clone(),
+            events::RefundGracePeriodUpdated {
+                grace_period,
+                updated_by: admin,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -1001,451 +1891,417 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Lock funds for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `depositor` - Address depositing the funds (must authorize)
-    /// * `bounty_id` - Unique identifier for this bounty
-    /// * `amount` - Token amount to lock (in smallest denomination)
-    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// Get the current refund grace period in seconds (view function)
+    pub fn get_refund_grace_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundGracePeriod)
+            .unwrap_or(0)
+    }
+
+    /// Set how many refund records each escrow stores (admin only).
     ///
-    /// # Returns
-    /// * `Ok(())` - Funds successfully locked
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    /// Once an escrow's `refund_history` reaches the cap, later refunds still
+    /// succeed but their records are emitted as `rf_over` events instead of
+    /// stored. Lowering the cap does not trim existing histories.
+    pub fn set_max_refund_history(env: Env, max_entries: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let old = Self::get_max_refund_history(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxRefundHistory, &max_entries);
+        Self::record_config_change(&env, symbol_short!("rf_hist"), old, max_entries, &admin);
+        Ok(())
+    }
+
+    /// Get the per-escrow refund history cap (view function)
+    pub fn get_max_refund_history(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxRefundHistory)
+            .unwrap_or(DEFAULT_MAX_REFUND_HISTORY)
+    }
+
+    /// Set the allowed lock duration band (admin only).
     ///
-    /// # State Changes
-    /// - Transfers `amount` tokens from depositor to contract
-    /// - Creates Escrow record in persistent storage
-    /// - Emits FundsLocked event
+    /// `lock_funds`, `batch_lock_funds` and `simulate_lock` reject deadlines
+    /// whose distance from now falls outside
+    /// `[min_deadline_offset, max_deadline_offset]` with `InvalidDeadline`.
     ///
-    /// # Authorization
-    /// - Depositor must authorize the transaction
-    /// - Depositor must have sufficient token balance
-    /// - Depositor must have approved contract for token transfer
-    ///
-    /// # Security Considerations
-    /// - Bounty ID must be unique (prevents overwrites)
-    /// - Amount must be positive (enforced by token contract)
-    /// - Deadline should be reasonable (recommended: 7-90 days)
-    /// - Token transfer is atomic with state update
-    ///
-    /// # Events
-    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
-    ///
-    /// # Example
-    /// ```rust
-    /// let depositor = Address::from_string("GDEPOSIT...");
-    /// let amount = 1000_0000000; // 1000 USDC
-    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
-    ///
-    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
-    /// // Funds are now locked and can be released or refunded
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage write + event emission
-    ///
-    /// # Common Pitfalls
-    /// - Forgetting to approve token contract before calling
-    /// - Using a bounty ID that already exists
-    /// - Setting deadline in the past or too far in the future
-    pub fn lock_funds(
+    /// # Errors
+    /// * `InvalidDeadline` - `min_deadline_offset` exceeds `max_deadline_offset`
+    pub fn set_deadline_policy(
         env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
+        min_deadline_offset: u64,
+        max_deadline_offset: u64,
     ) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
-        let start = env.ledger().timestamp();
-        let caller = depositor.clone();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            return Err(Error::ContractPaused);
+        if min_deadline_offset > max_deadline_offset {
+            return Err(Error::InvalidDeadline);
         }
 
-        // Verify depositor authorization
-        depositor.require_auth();
+        let old = Self::get_deadline_policy(env.clone());
+        let policy = DeadlinePolicy {
+            min_deadline_offset,
+            max_deadline_offset,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::DeadlinePolicy, &policy);
+        Self::record_config_change(&env, symbol_short!("dl_policy"), old, policy, &admin);
+        Ok(())
+    }
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
+    /// Get the allowed lock duration band (view function)
+    pub fn get_deadline_policy(env: Env) -> DeadlinePolicy {
         env.storage()
             .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
+            .get(&DataKey::DeadlinePolicy)
+            .unwrap_or(DeadlinePolicy {
+                min_deadline_offset: 0,
+                max_deadline_offset: MAX_DEADLINE_HORIZON,
+            })
+    }
 
-        if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::InvalidAmount);
+    /// Whether `deadline` is in the future and within the deadline policy
+    fn is_valid_deadline(env: &Env, deadline: u64) -> bool {
+        let now = env.ledger().timestamp();
+        if deadline <= now {
+            return false;
         }
+        let policy = Self::get_deadline_policy(env.clone());
+        let offset = deadline - now;
+        offset >= policy.min_deadline_offset && offset <= policy.max_deadline_offset
+    }
 
-        if deadline <= env.ledger().timestamp() {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::InvalidDeadline);
-        }
+    /// Set how long a settled escrow must be kept before `archive_escrow`
+    /// may remove it, in seconds (admin only).
+    pub fn set_archive_retention(env: Env, retention: u64) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::NotInitialized);
         }
 
-        // Prevent duplicate bounty IDs
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyExists);
-        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        let old = Self::get_archive_retention(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::ArchiveRetention, &retention);
+        Self::record_config_change(&env, symbol_short!("retention"), old, retention, &admin);
+        Ok(())
+    }
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.lock_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
+    /// Get the archive retention period in seconds (view function)
+    pub fn get_archive_retention(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ArchiveRetention)
+            .unwrap_or(DEFAULT_ARCHIVE_RETENTION)
+    }
 
-        // Transfer net amount from depositor to contract
-        client.transfer(&depositor, &env.current_contract_address(), &net_amount);
+    /// Timestamp from which a refund without approval is allowed.
+    /// Shared by `refund` and `get_refund_eligibility` so they always agree.
+    fn refund_open_at(env: &Env, deadline: u64) -> u64 {
+        let grace: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RefundGracePeriod)
+            .unwrap_or(0);
+        deadline.saturating_add(grace)
+    }
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(&depositor, &fee_config.fee_recipient, &fee_amount);
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Lock,
-                    amount: fee_amount,
-                    fee_rate: fee_config.lock_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
+    // ========================================================================
+    // Anti-Abuse Administrative Functions
+    // ========================================================================
+
+    /// Update the rate limit configuration (admin only)
+    pub fn update_rate_limit_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Create escrow record
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            amount: net_amount, // Store net amount (after fee)
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
-            remaining_amount: amount,
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        // Preserve the current enforcement mode
+        let old_config = anti_abuse::get_config(&env);
+        let config = anti_abuse::AntiAbuseConfig {
+            window_size,
+            max_operations,
+            cooldown_period,
+            mode: old_config.mode.clone(),
         };
+        anti_abuse::set_config(&env, config.clone());
+        Self::record_config_change(
+            &env,
+            symbol_short!("rate_lim"),
+            old_config,
+            config.clone(),
+            &admin,
+        );
 
-        // Store in persistent storage with extended TTL
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::emit_anti_abuse_config_updated(&env, config, admin);
 
-        // Emit event for off-chain indexing
-        // emit_funds_locked(
-        //     &env,
-        //     FundsLocked {
-        //         bounty_id,
-        //         amount: net_amount, // Emit net amount (after fee)
-        //         depositor: depositor.clone(),
-        //         deadline,
-        //     },
-        // );
-        on_funds_locked(&env, bounty_id, amount, &depositor, deadline);
+        Ok(())
+    }
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+    /// Switch rate limiting between `Enforce` and `Monitor` mode (admin only).
+    /// In `Monitor` mode violations still emit `abuse` events but never block the caller.
+    pub fn set_rate_limit_mode(env: Env, mode: anti_abuse::RateLimitMode) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+        let mut config = anti_abuse::get_config(&env);
+        let old_mode = config.mode.clone();
+        config.mode = mode;
+        anti_abuse::set_config(&env, config.clone());
+        Self::record_config_change(
+            &env,
+            symbol_short!("rate_mode"),
+            old_mode,
+            config.mode.clone(),
+            &admin,
+        );
+
+        Self::emit_anti_abuse_config_updated(&env, config, admin);
 
         Ok(())
     }
 
-    /// Sets or updates metadata for an existing escrow.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to attach metadata to
-    /// * `metadata` - Metadata structure containing repo, issue, type, and tags
+    /// Get the rate limit state for an address (view function).
     ///
     /// # Returns
-    /// * `Ok(())` - Metadata successfully set/updated
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::MetadataTooLarge)` - Metadata exceeds size limits
-    /// * `Err(Error::Unauthorized)` - Caller is not the depositor
-    ///
-    /// # State Changes
-    /// - Stores/updates metadata in persistent storage
-    /// - Extends storage TTL on access
-    ///
-    /// # Authorization
-    /// - Only the original depositor can set/update metadata
-    /// - This prevents unauthorized metadata modification
-    ///
-    /// # Size Limits
-    /// See `validate_metadata_size()` documentation for detailed limits.
-    ///
-    /// # Events
-    /// Emits: `FundsLocked` event with additional metadata field
-    ///
-    /// # Example
-    /// ```rust
-    /// let metadata = EscrowMetadata {
-    ///     repo_id: Some(String::from_str(&env, "owner/repo")),
-    ///     issue_id: Some(String::from_str(&env, "123")),
-    ///     bounty_type: Some(String::from_str(&env, "bug")),
-    ///     tags: vec![&env, String::from_str(&env, "priority-high")],
-    ///     custom_fields: map![&env],
-    /// };
-    ///
-    /// escrow_client.set_escrow_metadata(&42, &metadata)?;
-    /// ```
-    pub fn set_escrow_metadata(
-        env: Env,
-        bounty_id: u64,
-        metadata: EscrowMetadata,
-    ) -> Result<(), Error> {
-        // Verify bounty exists
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+    /// Tuple of `(operation_count, cooldown_remaining, would_block)`:
+    /// - operation_count: Operations performed in the current window
+    /// - cooldown_remaining: Seconds until the cooldown period expires
+    /// - would_block: Whether the next operation would be rejected
+    pub fn get_rate_limit_state(env: Env, address: Address) -> (u32, u64, bool) {
+        anti_abuse::get_rate_limit_state(&env, address)
+    }
 
-        // Get escrow to verify depositor authorization
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.depositor.require_auth();
+    /// Get the rate limit configuration in effect (view function).
+    /// Returns the defaults until `update_rate_limit_config` is first called.
+    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
+        anti_abuse::get_config(&env)
+    }
 
-        // Validate metadata size limits
-        if !validate_metadata_size(&env, &metadata) {
-            return Err(Error::MetadataTooLarge);
+    /// Emit the anti-abuse configuration event (internal helper)
+    fn emit_anti_abuse_config_updated(env: &Env, config: anti_abuse::AntiAbuseConfig, by: Address) {
+        events::emit_anti_abuse_config_updated(
+            env,
+            events::AntiAbuseConfigUpdated {
+                window_size: config.window_size,
+                max_operations: config.max_operations,
+                cooldown_period: config.cooldown_period,
+                mode: config.mode,
+                by,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Get every address on the rate limit whitelist (view function)
+    pub fn get_whitelisted(env: Env) -> Vec<Address> {
+        anti_abuse::get_whitelisted(&env)
+    }
+
+    /// Add or remove an address from the rate limit whitelist (admin only)
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Store metadata
-        env.storage()
-            .persistent()
-            .set(&DataKey::EscrowMetadata(bounty_id), &metadata);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Extend TTL for both escrow and metadata
-        env.storage().persistent().extend_ttl(
-            &DataKey::Escrow(bounty_id),
-            1000000,  // Minimum
-            10000000, // Maximum
+        let old = anti_abuse::is_whitelisted(&env, address.clone());
+        anti_abuse::set_whitelist(&env, address.clone(), whitelisted);
+        Self::record_config_change(
+            &env,
+            symbol_short!("whitelist"),
+            (address.clone(), old),
+            (address.clone(), whitelisted),
+            &admin,
         );
-        env.storage().persistent().extend_ttl(
-            &DataKey::EscrowMetadata(bounty_id),
-            1000000,  // Minimum
-            10000000, // Maximum
+
+        events::emit_whitelist_changed(
+            &env,
+            events::WhitelistChanged {
+                address,
+                whitelisted,
+                by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
     }
 
-    /// Releases escrowed funds to a contributor.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to release funds for
-    /// * `contributor` - Address to receive the funds
-    ///
-    /// # Returns
-    /// * `Ok(())` - Funds successfully released
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::Unauthorized)` - Caller is not the admin
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to contributor
-    /// - Updates escrow status to Released
-    /// - Emits FundsReleased event
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only admin can call this function
-    /// - Admin address must match initialization value
-    ///
-    /// # Security Considerations
-    /// - This is the most security-critical function
-    /// - Admin should verify task completion off-chain before calling
-    /// - Once released, funds cannot be retrieved
-    /// - Recipient address should be verified carefully
-    /// - Consider implementing multi-sig for admin
-    ///
-    /// # Events
-    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
-    ///
-    /// # Example
-    /// ```rust
-    /// // After verifying task completion off-chain:
-    /// let contributor = Address::from_string("GCONTRIB...");
-    ///
-    /// // Admin calls release
-    /// escrow_client.release_funds(&42, &contributor)?;
-    /// // Funds transferred to contributor, escrow marked as Released
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage update + event emission
-    ///
-    /// # Best Practices
-    /// 1. Verify contributor identity off-chain
-    /// 2. Confirm task completion before release
-    /// 3. Log release decisions in backend system
-    /// 4. Monitor release events for anomalies
-    /// 5. Consider implementing release delays for high-value bounties
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
+    // ========================================================================
+    // Pause and Emergency Functions
+    // ========================================================================
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
+    /// Check if contract is paused (internal helper)
+    fn is_paused_internal(env: &Env) -> bool {
         env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
+            .persistent()
+            .get::<_, bool>(&DataKey::IsPaused)
+            .unwrap_or(false)
+    }
+
+    /// Reject admin state changes while paused (internal helper).
+    /// Only `unpause` and `emergency_withdraw` remain available, so a pause
+    /// also contains a possibly compromised admin key.
+    fn ensure_not_paused(env: &Env) -> Result<(), Error> {
+        if Self::is_paused_internal(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Get pause status (view function)
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    /// Get the timestamp at which the contract was paused, or `None` if it is
+    /// not currently paused (view function)
+    pub fn get_paused_since(env: Env) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::PausedSince)
+    }
+
+    /// Pause the contract (admin only)
+    /// Prevents new fund locks, releases, and refunds, as well as every
+    /// admin mutation other than `unpause` and `emergency_withdraw`
+    pub fn pause(env: Env) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::NotInitialized);
         }
 
-        // Verify admin authorization
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // Check if contract is paused
         if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::ContractPaused);
+            return Ok(()); // Already paused, idempotent
         }
 
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+        env.storage().persistent().set(&DataKey::IsPaused, &true);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PausedSince, &env.ledger().timestamp());
+        Self::record_config_change(&env, symbol_short!("paused"), false, true, &admin);
 
-        admin.require_auth();
+        emit_contract_paused(
+            &env,
+            ContractPaused {
+                paused_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
-        // Verify bounty exists
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        Ok(())
+    }
+
+    /// Unpause the contract (admin only)
+    /// Resumes normal operations
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Get and verify escrow state
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        if escrow.status != EscrowStatus::Locked {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::FundsNotLocked);
+        if !Self::is_paused_internal(&env) {
+            return Ok(()); // Already unpaused, idempotent
         }
 
-        // Transfer funds to contributor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        escrow.status = EscrowStatus::Released;
+        env.storage().persistent().set(&DataKey::IsPaused, &false);
+        env.storage().persistent().remove(&DataKey::PausedSince);
+        Self::record_config_change(&env, symbol_short!("paused"), true, false, &admin);
+
+        emit_contract_unpaused(
+            &env,
+            ContractUnpaused {
+                unpaused_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Check if the contract has been sunset (view function)
+    pub fn is_sunset(env: Env) -> bool {
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .get::<_, bool>(&DataKey::Sunset)
+            .unwrap_or(false)
+    }
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, fee_config.release_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = escrow.amount - fee_amount;
+    /// Permanently stop accepting new locks (admin only)
+    /// Unlike `pause` this cannot be undone, not even by a future admin.
+    /// Releases and refunds stay available so existing escrows can drain.
+    pub fn sunset(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_config.fee_recipient,
-                &fee_amount,
-            );
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Release,
-                    amount: fee_amount,
-                    fee_rate: fee_config.release_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
+        if Self::is_sunset(env.clone()) {
+            return Ok(()); // Already sunset, idempotent
         }
 
-        // Update escrow state - mark as released and set remaining_amount to 0
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Emit release event
-        // emit_funds_released(
-        //     &env,
-        //     FundsReleased {
-        //         bounty_id,
-        //         amount: net_amount, // Emit net amount (after fee)
-        //         recipient: contributor.clone(),
-        //         timestamp: env.ledger().timestamp(),
-        //     },
-        // );
+        env.storage().persistent().set(&DataKey::Sunset, &true);
+        Self::record_config_change(&env, symbol_short!("sunset"), false, true, &admin);
 
-        // Emit release event
-        on_funds_released(
+        events::emit_contract_sunset(
             &env,
-            bounty_id,
-            net_amount,
-            &contributor,
-            escrow.remaining_amount,
-            false,
+            events::ContractSunset {
+                sunset_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
-
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("release"), duration);
         Ok(())
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
+    /// Emergency withdrawal for all contract funds (admin only, only when paused)
+    /// This function allows admins to recover all contract funds in case of critical
+    /// security issues or unrecoverable bugs. It can only be called when the contract
+    /// is paused to prevent misuse.
+    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
@@ -1453,454 +2309,3324 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        // Only allow emergency withdrawal when contract is paused
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
         }
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
+        // Get contract balance
+        let balance = client.balance(&env.current_contract_address());
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        if balance <= 0 {
+            return Ok(()); // No funds to withdraw
         }
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
+        // Transfer all funds to recipient
+        client.transfer(&env.current_contract_address(), &recipient, &balance);
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+        emit_emergency_withdrawal(
+            &env,
+            EmergencyWithdrawal {
+                withdrawn_by: admin.clone(),
+                amount: balance,
+                recipient: recipient.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
         Ok(())
     }
 
-    /// Refund funds with support for Full, Partial, and Custom refunds.
-    /// - Full: refunds all remaining funds to depositor
-    /// - Partial: refunds specified amount to depositor
-    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
-    pub fn refund(
+    /// Emergency payout of contract funds to several recipients (admin only,
+    /// only when paused).
+    ///
+    /// Like `emergency_withdraw`, but splits the payout so a wind-down can
+    /// return funds to each depositor directly. Every amount must be positive
+    /// and their sum must not exceed the contract balance; nothing is
+    /// transferred otherwise. Emits an `EmergencyWithdrawal` per recipient.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - The contract is not paused
+    /// * `InvalidBatchSize` - `distributions` is empty or exceeds `MAX_BATCH_SIZE`
+    /// * `InvalidAmount` - An amount is not positive, or the sum overflows
+    /// * `InsufficientFunds` - The sum exceeds the contract balance
+    pub fn emergency_distribute(
         env: Env,
-        bounty_id: u64,
-        amount: Option<i128>,
-        recipient: Option<Address>,
-        mode: RefundMode,
+        distributions: Vec<(Address, i128)>,
     ) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
-
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            return Err(Error::ContractPaused);
-        }
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Get and verify escrow state
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        let caller = escrow.depositor.clone();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
         }
 
-        // Verify deadline has passed
-        let now = env.ledger().timestamp();
-        let is_before_deadline = now < escrow.deadline;
-
-        // Determine refund amount and recipient
-        let refund_amount: i128;
-        let refund_recipient: Address;
-
-        match mode {
-            RefundMode::Full => {
-                refund_amount = escrow.remaining_amount;
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Partial => {
-                refund_amount = amount.unwrap_or(escrow.remaining_amount);
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Custom => {
-                refund_amount = amount.ok_or(Error::InvalidAmount)?;
-                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
-
-                // Custom refunds before deadline require admin approval
-                if is_before_deadline {
-                    if !env
-                        .storage()
-                        .persistent()
-                        .has(&DataKey::RefundApproval(bounty_id))
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-                    let approval: RefundApproval = env
-                        .storage()
-                        .persistent()
-                        .get(&DataKey::RefundApproval(bounty_id))
-                        .unwrap();
-
-                    // Verify approval matches request
-                    if approval.amount != refund_amount
-                        || approval.recipient != refund_recipient
-                        || approval.mode != mode
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-
-                    // Clear approval after use
-                    env.storage()
-                        .persistent()
-                        .remove(&DataKey::RefundApproval(bounty_id));
-                }
-            }
+        if distributions.is_empty() || distributions.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
         }
 
-        // Validate amount
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        let mut total: i128 = 0;
+        for (_, amount) in distributions.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
         }
 
-        // Transfer funds back to depositor
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-
-        // Check contract balance
-        let contract_balance = client.balance(&env.current_contract_address());
-        if contract_balance < refund_amount {
+        let contract_address = env.current_contract_address();
+        if total > client.balance(&contract_address) {
             return Err(Error::InsufficientFunds);
         }
 
-        // Transfer funds
-        client.transfer(
-            &env.current_contract_address(),
-            &refund_recipient,
-            &refund_amount,
-        );
+        for (recipient, amount) in distributions.iter() {
+            client.transfer(&contract_address, &recipient, &amount);
+            emit_emergency_withdrawal(
+                &env,
+                EmergencyWithdrawal {
+                    withdrawn_by: admin.clone(),
+                    amount,
+                    recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
-        // Update escrow state
-        escrow.remaining_amount -= refund_amount;
+        Ok(())
+    }
 
-        // Add to refund history
-        let refund_record = RefundRecord {
-            amount: refund_amount,
-            recipient: refund_recipient.clone(),
-            mode: mode.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        escrow.refund_history.push_back(refund_record);
+    /// Check whether the reentrancy guard is currently set (view function)
+    ///
+    /// The guard only lives for the duration of a mutating call, so `true`
+    /// outside a transaction means it is stuck. Only the mutating entry
+    /// points (`lock_funds`, `release_funds`, `refund`) check the guard;
+    /// view functions never do and stay callable while it is held.
+    pub fn is_reentrancy_locked(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::ReentrancyGuard)
+    }
 
-        // Update status
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
+    /// Clear a stuck reentrancy guard (admin only, break-glass recovery)
+    pub fn clear_reentrancy_lock(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
+
+    /// Check if a bounty is frozen (internal helper)
+    fn is_bounty_frozen_internal(env: &Env, bounty_id: u64) -> bool {
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .get::<_, bool>(&DataKey::FrozenBounty(bounty_id))
+            .unwrap_or(false)
+    }
 
-        // Emit refund event
-        // emit_funds_refunded(
-        //     &env,
-        //     FundsRefunded {
-        //         bounty_id,
-        //         amount: refund_amount,
-        //         refund_to: refund_recipient,
-        //         timestamp: env.ledger().timestamp(),
-        //         refund_mode: mode.clone(),
-        //         remaining_amount: escrow.remaining_amount,
-        //     },
-        // );
+    /// Get frozen status of a single bounty (view function)
+    pub fn is_bounty_frozen(env: Env, bounty_id: u64) -> bool {
+        Self::is_bounty_frozen_internal(&env, bounty_id)
+    }
 
-        // Emit refund event
-        on_funds_refunded(
-            &env,
-            bounty_id,
-            refund_amount,
-            &refund_recipient,
-            escrow.remaining_amount,
-            mode,
-            &caller,
-        );
+    /// Move a locked escrow from `old_id` to `new_id` (admin only).
+    ///
+    /// Maintenance primitive for reconciling bounty ids with off-chain records.
+    /// No tokens move; the escrow record, any pending refund or release state,
+    /// deadline alert subscriptions, the client reference registered through
+    /// `lock_funds_with_ref` and the query indices are re-keyed.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `BountyNotFound` - `old_id` has no escrow
+    /// * `FundsNotLocked` - Escrow is not in `Locked` status
+    /// * `BountyFrozen` - Escrow is frozen
+    /// * `BountyExists` - `new_id` already has an escrow
+    pub fn reassign_bounty(env: Env, old_id: u64, new_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(old_id))
+            .ok_or(Error::BountyNotFound)?;
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
 
-        Ok(())
-    }
+        if Self::is_bounty_frozen_internal(&env, old_id) {
+            return Err(Error::BountyFrozen);
+        }
 
-    // ========================================================================
-    // View Functions (Read-only)
-    // ========================================================================
+        if Self::is_bounty_id_taken(&env, new_id) {
+            return Err(Error::BountyExists);
+        }
 
-    /// Retrieves escrow information for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok(Escrow)` - The complete escrow record
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    ///
-    /// # Example
-    /// ```rust
-    /// let escrow_info = escrow_client.get_escrow_info(&42)?;
-    /// println!("Amount: {}", escrow_info.amount);
-    /// println!("Status: {:?}", escrow_info.status);
-    /// println!("Deadline: {}", escrow_info.deadline);
-    /// ```
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        Self::save_escrow(&env, new_id, &mut escrow);
+        env.storage().persistent().remove(&DataKey::Escrow(old_id));
+
+        let approval: Option<RefundApproval> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(old_id));
+        if let Some(mut approval) = approval {
+            approval.bounty_id = new_id;
+            env.storage()
+                .persistent()
+                .set(&DataKey::RefundApproval(new_id), &approval);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RefundApproval(old_id));
         }
-        Ok(env
+
+        let release_request: Option<ReleaseRequest> = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
-    }
+            .get(&DataKey::ReleaseRequest(old_id));
+        if let Some(mut release_request) = release_request {
+            release_request.bounty_id = new_id;
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseRequest(new_id), &release_request);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseRequest(old_id));
+        }
 
-    /// Retrieves metadata for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok(Option<EscrowMetadata>)` - Metadata if set, None if not set
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    ///
-    /// # Example
-    /// ```rust
-    /// let metadata_opt = escrow_client.get_escrow_metadata(&42)?;
-    /// if let Some(metadata) = metadata_opt {
-    ///     println!("Repo: {:?}", metadata.repo_id);
-    ///     println!("Issue: {:?}", metadata.issue_id);
-    ///     println!("Type: {:?}", metadata.bounty_type);
-    ///     println!("Tags: {:?}", metadata.tags);
-    /// }
-    /// ```
-    pub fn get_escrow_metadata(env: Env, bounty_id: u64) -> Result<Option<EscrowMetadata>, Error> {
-        // Verify bounty exists
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        let release_approvals: Option<Vec<Address>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseApprovals(old_id));
+        if let Some(release_approvals) = release_approvals {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseApprovals(new_id), &release_approvals);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseApprovals(old_id));
         }
 
-        // Get metadata if it exists
-        let metadata: Option<EscrowMetadata> = env
+        let destination: Option<Address> = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowMetadata(bounty_id));
-        Ok(metadata)
-    }
+            .get(&DataKey::RefundDestination(old_id));
+        if let Some(destination) = destination {
+            env.storage()
+                .persistent()
+                .set(&DataKey::RefundDestination(new_id), &destination);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RefundDestination(old_id));
+        }
 
-    /// Retrieves complete escrow information including metadata.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
+        let subscribers: Option<Vec<Address>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DeadlineSubscribers(old_id));
+        if let Some(subscribers) = subscribers {
+            env.storage()
+                .persistent()
+                .set(&DataKey::DeadlineSubscribers(new_id), &subscribers);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::DeadlineSubscribers(old_id));
+        }
+
+        // Carry the sent marker too, so alerts already sent aren't repeated
+        let alert_sent: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DeadlineAlertSent(old_id));
+        if let Some(alert_sent) = alert_sent {
+            env.storage()
+                .persistent()
+                .set(&DataKey::DeadlineAlertSent(new_id), &alert_sent);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::DeadlineAlertSent(old_id));
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::LockFeeAccrued(old_id))
+        {
+            env.storage()
+                .persistent()
+                .set(&DataKey::LockFeeAccrued(new_id), &true);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::LockFeeAccrued(old_id));
+        }
+
+        let client_ref: Option<(Address, BytesN<32>)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyClientRef(old_id));
+        if let Some((depositor, client_ref)) = client_ref {
+            env.storage().persistent().set(
+                &DataKey::ClientRef(depositor.clone(), client_ref.clone()),
+                &new_id,
+            );
+            env.storage()
+                .persistent()
+                .set(&DataKey::BountyClientRef(new_id), &(depositor, client_ref));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::BountyClientRef(old_id));
+        }
+
+        on_bounty_reassigned(&env, old_id, new_id, &admin);
+        Self::bump_escrow_ttl_internal(&env, new_id);
+
+        Ok(())
+    }
+
+    /// Rebuild the query indices for the given bounties from their escrow
+    /// records (admin only).
+    ///
+    /// Backfills escrows locked before the indexing layer existed and repairs
+    /// index entries that expired while the escrow survived. Safe to repeat.
+    ///
+    /// # Returns
+    /// Number of bounties reindexed
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - empty or more than `MAX_BATCH_SIZE` ids
+    /// * `BountyNotFound` - any id has no escrow (nothing is reindexed)
+    pub fn reindex(env: Env, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut escrows = Vec::new(&env);
+        for bounty_id in bounty_ids.iter() {
+            escrows.push_back(Self::get_escrow_info(env.clone(), bounty_id)?);
+        }
+
+        for (bounty_id, escrow) in bounty_ids.iter().zip(escrows.iter()) {
+            rebuild_bounty_index(&env, bounty_id, &escrow);
+        }
+
+        Ok(bounty_ids.len())
+    }
+
+    /// Freeze a single bounty (admin only)
+    /// Blocks release and refund for this bounty only, e.g. during a compliance review.
+    /// Unlike `pause`, all other escrows keep operating normally.
+    pub fn freeze_bounty(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::freeze_bounty_internal(env, bounty_id, true)
+    }
+
+    /// Body of `freeze_bounty` (see `release_funds_internal` for the flag)
+    fn freeze_bounty_internal(
+        env: Env,
+        bounty_id: u64,
+        require_admin_auth: bool,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if require_admin_auth {
+            admin.require_auth();
+        }
+        Self::ensure_not_paused(&env)?;
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        if Self::is_bounty_frozen_internal(&env, bounty_id) {
+            return Ok(()); // Already frozen, idempotent
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FrozenBounty(bounty_id), &true);
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        on_bounty_freeze_changed(&env, bounty_id, &escrow);
+
+        events::emit_bounty_frozen(
+            &env,
+            events::BountyFrozen {
+                bounty_id,
+                frozen_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Unfreeze a previously frozen bounty (admin only)
+    pub fn unfreeze_bounty(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::unfreeze_bounty_internal(env, bounty_id, true)
+    }
+
+    /// Body of `unfreeze_bounty` (see `release_funds_internal` for the flag)
+    fn unfreeze_bounty_internal(
+        env: Env,
+        bounty_id: u64,
+        require_admin_auth: bool,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if require_admin_auth {
+            admin.require_auth();
+        }
+        Self::ensure_not_paused(&env)?;
+
+        if !Self::is_bounty_frozen_internal(&env, bounty_id) {
+            return Ok(()); // Not frozen, idempotent
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FrozenBounty(bounty_id));
+        if let Ok(escrow) = Self::get_escrow_info(env.clone(), bounty_id) {
+            on_bounty_freeze_changed(&env, bounty_id, &escrow);
+        }
+
+        events::emit_bounty_unfrozen(
+            &env,
+            events::BountyUnfrozen {
+                bounty_id,
+                unfrozen_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Lock funds and record a client-chosen reference for later lookup.
+    ///
+    /// Behaves like `lock_funds`, and additionally indexes
+    /// `(depositor, client_ref) -> bounty_id` so a client that lost the
+    /// response can recover the escrow via `get_escrow_by_ref`. Retrying with
+    /// the same reference and bounty ID is a no-op.
+    ///
+    /// # Errors
+    /// * `BountyExists` - `client_ref` already points to a different bounty
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_with_ref(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        client_ref: BytesN<32>,
+    ) -> Result<(), Error> {
+        let ref_key = DataKey::ClientRef(depositor.clone(), client_ref.clone());
+        if let Some(existing) = env.storage().persistent().get::<_, u64>(&ref_key) {
+            if existing == bounty_id {
+                return Ok(());
+            }
+            return Err(Error::BountyExists);
+        }
+
+        Self::lock_funds(env.clone(), depositor.clone(), bounty_id, amount, deadline)?;
+        env.storage().persistent().set(&ref_key, &bounty_id);
+        env.storage().persistent().set(
+            &DataKey::BountyClientRef(bounty_id),
+            &(depositor, client_ref),
+        );
+
+        Ok(())
+    }
+
+    /// Lock funds under a contract-assigned bounty id and return that id.
+    ///
+    /// Ids come from a monotonic counter starting at 1, skipping any id
+    /// already taken through the explicit-id `lock_funds`, so clients never
+    /// have to pick or coordinate ids themselves.
+    ///
+    /// # Errors
+    /// Same as `lock_funds`
+    pub fn lock_funds_auto_id(
+        env: Env,
+        depositor: Address,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<u64, Error> {
+        let mut bounty_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextBountyId)
+            .unwrap_or(1);
+        while Self::is_bounty_id_taken(&env, bounty_id) {
+            bounty_id += 1;
+        }
+
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::NextBountyId, &(bounty_id + 1));
+
+        Ok(bounty_id)
+    }
+
+    /// Lock fee charged on `amount` under `fee_config` (internal helper)
+    #[cfg(feature = "fees")]
+    fn lock_fee(fee_config: &FeeConfig, amount: i128) -> i128 {
+        if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.lock_fee_rate, fee_config.rounding_mode)
+        } else {
+            0
+        }
+    }
+
+    /// Release fee charged on `amount` under `fee_config` (internal helper)
+    #[cfg(feature = "fees")]
+    fn release_fee(fee_config: &FeeConfig, amount: i128) -> i128 {
+        if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
+            Self::calculate_fee(
+                amount,
+                fee_config.release_fee_rate,
+                fee_config.rounding_mode,
+            )
+        } else {
+            0
+        }
+    }
+
+    /// Release fee on `amount` and where it would go, without moving funds
+    #[cfg(feature = "fees")]
+    fn preview_release_fee(env: &Env, amount: i128) -> (i128, Option<Address>) {
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = Self::release_fee(&fee_config, amount);
+        if fee_amount == 0 {
+            (0, None)
+        } else if Self::is_fee_accrual_enabled(env) {
+            (fee_amount, Some(env.current_contract_address()))
+        } else {
+            (fee_amount, Some(fee_config.fee_recipient))
+        }
+    }
+
+    /// Without the `fees` feature no release fee is ever charged
+    #[cfg(not(feature = "fees"))]
+    fn preview_release_fee(_env: &Env, _amount: i128) -> (i128, Option<Address>) {
+        (0, None)
+    }
+
+    /// Take the release fee on `amount` out of the contract's balance, either
+    /// paying it to the fee recipient or accruing it, and return the fee.
+    /// Partial releases pass only the portion being released, so the fees
+    /// over a series of partials add up to the fee on one full release
+    /// (up to per-release rounding).
+    #[cfg(feature = "fees")]
+    fn collect_release_fee(env: &Env, client: &token::Client, amount: i128) -> i128 {
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = Self::release_fee(&fee_config, amount);
+        if fee_amount == 0 {
+            return 0;
+        }
+
+        let recipient = if Self::is_fee_accrual_enabled(env) {
+            Self::accrue_fee(env, fee_amount);
+            env.current_contract_address()
+        } else {
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &fee_amount,
+            );
+            fee_config.fee_recipient.clone()
+        };
+        events::emit_fee_collected(
+            env,
+            events::FeeCollected {
+                operation_type: events::FeeOperationType::Release,
+                amount: fee_amount,
+                fee_rate: fee_config.release_fee_rate,
+                recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        fee_amount
+    }
+
+    /// Without the `fees` feature no release fee is ever charged
+    #[cfg(not(feature = "fees"))]
+    fn collect_release_fee(_env: &Env, _client: &token::Client, _amount: i128) -> i128 {
+        0
+    }
+
+    /// Pull a deposit of `amount` for `bounty_id` from the depositor, charging
+    /// the lock fee if enabled, and return the fee (internal helper). The fee
+    /// is either paid to the fee recipient or, with accrual on, kept in the
+    /// contract and marked on the bounty.
+    #[cfg(feature = "fees")]
+    fn take_deposit(
+        env: &Env,
+        client: &token::Client,
+        bounty_id: u64,
+        depositor: &Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = Self::lock_fee(&fee_config, amount);
+        let net_amount = amount - fee_amount;
+        let accrue = fee_amount > 0 && Self::is_fee_accrual_enabled(env);
+
+        // Transfer net amount from depositor to contract
+        if accrue {
+            // Fee stays in the contract alongside the escrowed funds
+            Self::transfer_in(client, depositor, &env.current_contract_address(), amount)?;
+            Self::accrue_fee(env, fee_amount);
+            env.storage()
+                .persistent()
+                .set(&DataKey::LockFeeAccrued(bounty_id), &true);
+        } else {
+            Self::transfer_in(
+                client,
+                depositor,
+                &env.current_contract_address(),
+                net_amount,
+            )?;
+        }
+
+        // Transfer fee to fee recipient if applicable
+        if fee_amount > 0 {
+            let recipient = if accrue {
+                env.current_contract_address()
+            } else {
+                Self::transfer_in(client, depositor, &fee_config.fee_recipient, fee_amount)?;
+                fee_config.fee_recipient.clone()
+            };
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Lock,
+                    amount: fee_amount,
+                    fee_rate: fee_config.lock_fee_rate,
+                    recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        Ok(fee_amount)
+    }
+
+    /// Without the `fees` feature the whole deposit is escrowed
+    #[cfg(not(feature = "fees"))]
+    fn take_deposit(
+        env: &Env,
+        client: &token::Client,
+        _bounty_id: u64,
+        depositor: &Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::transfer_in(client, depositor, &env.current_contract_address(), amount)?;
+        Ok(0)
+    }
+
+    /// Dry-run `lock_funds` without transferring tokens or writing state.
+    ///
+    /// Runs the same precondition checks as `lock_funds` (paused,
+    /// initialization, amount, deadline, bounty id availability) and returns
+    /// `(net_locked, fee)` for `amount` under the current fee configuration.
+    /// Depositor balance and authorization are not checked.
+    pub fn simulate_lock(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(i128, i128), Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if Self::is_sunset(env.clone()) {
+            return Err(Error::LocksDisabled);
+        }
+
+        if amount < MIN_LOCK_AMOUNT {
+            return Err(Error::InvalidAmount);
+        }
+
+        if !Self::is_valid_deadline(&env, deadline) {
+            return Err(Error::InvalidDeadline);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if Self::is_bounty_id_taken(&env, bounty_id) {
+            return Err(Error::BountyExists);
+        }
+
+        #[cfg(feature = "fees")]
+        let fee = Self::lock_fee(&Self::get_fee_config_internal(&env), amount);
+        #[cfg(not(feature = "fees"))]
+        let fee = 0;
+        Ok((amount - fee, fee))
+    }
+
+    /// Lock funds now but only allow release or refund from `active_from`.
+    ///
+    /// Tokens are transferred at lock time exactly as in `lock_funds`; until
+    /// `active_from`, `release_funds`, `refund` and the batch release
+    /// functions reject this bounty with `NotYetActive`.
+    ///
+    /// # Errors
+    /// Same as `lock_funds`, plus:
+    /// * `InvalidDeadline` - `active_from` is after `deadline`
+    pub fn lock_funds_scheduled(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        active_from: u64,
+    ) -> Result<(), Error> {
+        if active_from > deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.active_from = active_from;
+        Self::save_escrow(&env, bounty_id, &mut escrow);
+
+        Ok(())
+    }
+
+    /// Whether the escrow's `active_from` time has been reached (internal helper)
+    fn is_escrow_active(env: &Env, escrow: &Escrow) -> bool {
+        env.ledger().timestamp() >= escrow.active_from
+    }
+
+    /// Look up an escrow by the depositor's own reference.
+    ///
+    /// # Returns
+    /// * `Ok((bounty_id, Escrow))` - The escrow locked with this reference
+    /// * `Err(Error::BountyNotFound)` - No escrow was locked with this reference
+    pub fn get_escrow_by_ref(
+        env: Env,
+        depositor: Address,
+        client_ref: BytesN<32>,
+    ) -> Result<(u64, Escrow), Error> {
+        let bounty_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ClientRef(depositor, client_ref))
+            .ok_or(Error::BountyNotFound)?;
+        let escrow = Self::get_escrow_info(env, bounty_id)?;
+        Ok((bounty_id, escrow))
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully locked
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    /// * `Err(Error::TransferFailed)` - Token transfer from the depositor failed
+    ///   (insufficient balance or authorization)
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from depositor to contract
+    /// - Creates Escrow record in persistent storage
+    /// - Emits FundsLocked event
+    ///
+    /// # Authorization
+    /// - Depositor must authorize the transaction
+    /// - Depositor must have sufficient token balance
+    /// - Depositor must have approved contract for token transfer
+    ///
+    /// # Security Considerations
+    /// - Bounty ID must be unique (prevents overwrites)
+    /// - Amount must be positive (enforced by token contract)
+    /// - Deadline should be reasonable (recommended: 7-90 days)
+    /// - Token transfer is atomic with state update
+    ///
+    /// # Events
+    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
+    ///
+    /// # Example
+    /// ```rust
+    /// let depositor = Address::from_string("GDEPOSIT...");
+    /// let amount = 1000_0000000; // 1000 USDC
+    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    ///
+    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
+    /// // Funds are now locked and can be released or refunded
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage write + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to approve token contract before calling
+    /// - Using a bounty ID that already exists
+    /// - Setting deadline in the past or too far in the future
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        // Verify depositor authorization before touching their rate-limit state
+        depositor.require_auth();
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+
+        let start = env.ledger().timestamp();
+        let caller = depositor.clone();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        if Self::is_sunset(env.clone()) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::LocksDisabled);
+        }
+
+        // Ensure contract is initialized
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        if amount < MIN_LOCK_AMOUNT {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::InvalidAmount);
+        }
+
+        if !Self::is_valid_deadline(&env, deadline) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::InvalidDeadline);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::NotInitialized);
+        }
+
+        // High-value deposits also need the admin's co-signature
+        if Self::requires_admin_cosign(&env, amount) {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
+
+        // Prevent duplicate bounty IDs
+        if Self::is_bounty_id_taken(&env, bounty_id) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyExists);
+        }
+
+        // Get token contract and transfer funds
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Transfer funds in, collecting the lock fee if enabled
+        let fee_amount = match Self::take_deposit(&env, &client, bounty_id, &depositor, amount) {
+            Ok(fee_amount) => fee_amount,
+            Err(err) => {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+                env.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(err);
+            }
+        };
+        let net_amount = amount - fee_amount;
+
+        // Create escrow record
+        let mut escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: net_amount, // Store net amount (after fee)
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![&env],
+            remaining_amount: net_amount,
+            active_from: 0,
+            gross_amount: amount,
+            lock_fee_paid: fee_amount,
+            updated_at: 0,
+            updated_seq: 0,
+        };
+
+        // Store in persistent storage with extended TTL
+        Self::save_escrow(&env, bounty_id, &mut escrow);
+        Self::adjust_total_locked(&env, net_amount);
+        Self::count_new_escrow(&env, &depositor);
+
+        // Emit event for off-chain indexing
+        // emit_funds_locked(
+        //     &env,
+        //     FundsLocked {
+        //         bounty_id,
+        //         amount: net_amount, // Emit net amount (after fee)
+        //         depositor: depositor.clone(),
+        //         deadline,
+        //     },
+        // );
+        on_funds_locked(&env, bounty_id, amount, &depositor, deadline);
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+
+        Ok(())
+    }
+
+    /// Sets or updates metadata for an existing escrow.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to attach metadata to
+    /// * `metadata` - Metadata structure containing repo, issue, type, and tags
+    ///
+    /// # Returns
+    /// * `Ok(())` - Metadata successfully set/updated
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::MetadataTooLarge)` - Metadata exceeds size limits
+    /// * `Err(Error::Unauthorized)` - Caller is not the depositor
+    ///
+    /// # State Changes
+    /// - Stores/updates metadata in persistent storage
+    /// - Extends storage TTL on access
+    ///
+    /// # Authorization
+    /// - Only the original depositor can set/update metadata
+    /// - This prevents unauthorized metadata modification
+    ///
+    /// # Size Limits
+    /// See `validate_metadata_size()` documentation for detailed limits.
+    ///
+    /// # Events
+    /// Emits: `FundsLocked` event with additional metadata field
+    ///
+    /// # Example
+    /// ```rust
+    /// let metadata = EscrowMetadata {
+    ///     repo_id: Some(String::from_str(&env, "owner/repo")),
+    ///     issue_id: Some(String::from_str(&env, "123")),
+    ///     bounty_type: Some(String::from_str(&env, "bug")),
+    ///     tags: vec![&env, String::from_str(&env, "priority-high")],
+    ///     custom_fields: map![&env],
+    /// };
+    ///
+    /// escrow_client.set_escrow_metadata(&42, &metadata)?;
+    /// ```
+    pub fn set_escrow_metadata(
+        env: Env,
+        bounty_id: u64,
+        metadata: EscrowMetadata,
+    ) -> Result<(), Error> {
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get escrow to verify depositor authorization
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.depositor.require_auth();
+
+        // Validate metadata size limits
+        if !validate_metadata_size(&env, &metadata) {
+            return Err(Error::MetadataTooLarge);
+        }
+
+        // Store metadata
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowMetadata(bounty_id), &metadata);
+
+        // Extend TTL for both escrow and metadata
+        env.storage().persistent().extend_ttl(
+            &DataKey::Escrow(bounty_id),
+            1000000,  // Minimum
+            10000000, // Maximum
+        );
+        env.storage().persistent().extend_ttl(
+            &DataKey::EscrowMetadata(bounty_id),
+            1000000,  // Minimum
+            10000000, // Maximum
+        );
+
+        Ok(())
+    }
+
+    /// Get the admins allowed to approve releases (empty for single-admin deployments)
+    pub fn get_release_approvers(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReleaseApprovers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the number of approvals a release needs (0 for single-admin deployments)
+    pub fn get_release_approvals_required(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReleaseApprovalsRequired)
+            .unwrap_or(0)
+    }
+
+    /// Get the approvals recorded so far for a bounty's next release
+    pub fn get_release_approvals(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseApprovals(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Record `approver`'s approval to release `bounty_id` (approvers only).
+    /// Approving twice is a no-op. Returns the number of approvals so far.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `Unauthorized` - `approver` is not in the approver set
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Nothing left to release
+    pub fn approve_release(env: Env, approver: Address, bounty_id: u64) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        approver.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        if !Self::get_release_approvers(env.clone()).contains(&approver) {
+            return Err(Error::Unauthorized);
+        }
+
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let mut approvals = Self::get_release_approvals(env.clone(), bounty_id);
+        if !approvals.contains(&approver) {
+            approvals.push_back(approver);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReleaseApprovals(bounty_id), &approvals);
+        }
+
+        Ok(approvals.len())
+    }
+
+    /// Ensure a bounty has enough approvals to be released (internal helper)
+    fn check_release_approvals(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        let approvals = Self::get_release_approvals(env.clone(), bounty_id);
+        if approvals.len() < Self::get_release_approvals_required(env.clone()) {
+            return Err(Error::ReleaseNotApproved);
+        }
+        Ok(())
+    }
+
+    /// With a release timelock set, ensure a bounty has a matured release
+    /// request for `contributor` (internal helper)
+    fn check_release_request(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+    ) -> Result<(), Error> {
+        if Self::get_release_timelock(env.clone()) == 0 {
+            return Ok(());
+        }
+        let request = Self::get_release_request(env.clone(), bounty_id)
+            .filter(|request| request.contributor == *contributor)
+            .ok_or(Error::ReleaseNotRequested)?;
+        if env.ledger().timestamp() < request.executable_after {
+            return Err(Error::ReleaseTimelocked);
+        }
+        Ok(())
+    }
+
+    /// Releases escrowed funds to a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release funds for
+    /// * `contributor` - Address to receive the funds
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully released
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - Caller is not the admin
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow already released or refunded
+    ///
+    /// # State Changes
+    /// - Transfers `remaining_amount` (less any release fee) to contributor
+    /// - Updates escrow status to Released
+    /// - Emits FundsReleased event
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only admin can call this function
+    /// - Admin address must match initialization value
+    ///
+    /// # Security Considerations
+    /// - This is the most security-critical function
+    /// - Admin should verify task completion off-chain before calling
+    /// - Once released, funds cannot be retrieved
+    /// - Recipient address should be verified carefully
+    /// - Deployments created with `init_with_approvers` additionally need the
+    ///   configured number of `approve_release` calls (`ReleaseNotApproved` otherwise)
+    /// - With a release timelock set, the bounty needs a matured
+    ///   `request_release` for `contributor` (`ReleaseNotRequested` or
+    ///   `ReleaseTimelocked` otherwise); the request is consumed on release
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
+    ///
+    /// # Example
+    /// ```rust
+    /// // After verifying task completion off-chain:
+    /// let contributor = Address::from_string("GCONTRIB...");
+    ///
+    /// // Admin calls release
+    /// escrow_client.release_funds(&42, &contributor)?;
+    /// // Funds transferred to contributor, escrow marked as Released
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage update + event emission
+    ///
+    /// # Best Practices
+    /// 1. Verify contributor identity off-chain
+    /// 2. Confirm task completion before release
+    /// 3. Log release decisions in backend system
+    /// 4. Monitor release events for anomalies
+    /// 5. Consider implementing release delays for high-value bounties
+    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        Self::release_funds_internal(env, bounty_id, contributor, true)
+    }
+
+    /// Body of `release_funds`; `require_admin_auth` is false only when the
+    /// caller has already authorized the admin (see `batch_admin_ops`).
+    fn release_funds_internal(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        require_admin_auth: bool,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Ensure contract is initialized
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify admin authorization
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::ContractPaused);
+        }
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, admin.clone());
+
+        if require_admin_auth {
+            admin.require_auth();
+        }
+
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        // Partial activity is fine: only remaining_amount is paid out
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyReleased
+            && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::FundsNotLocked);
+        }
+
+        if Self::is_bounty_frozen_internal(&env, bounty_id) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyFrozen);
+        }
+
+        if !Self::is_escrow_active(&env, &escrow) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::NotYetActive);
+        }
+
+        if let Err(err) = Self::check_release_approvals(&env, bounty_id)
+            .and_then(|_| Self::check_recipient(&env, &escrow, &contributor))
+            .and_then(|_| Self::check_release_request(&env, bounty_id, &contributor))
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
+        // Conservation guard: never pay out more than the escrow still holds
+        if escrow.remaining_amount <= 0 || escrow.remaining_amount > escrow.amount {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::InsufficientFunds);
+        }
+
+        // Pay out the live balance, not the original deposit
+        let release_amount = escrow.remaining_amount;
+
+        // Settle escrow state before any external call (checks-effects-interactions),
+        // so a contract contributor can't observe or reuse a still-locked escrow
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        Self::save_escrow(&env, bounty_id, &mut escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseApprovals(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseRequest(bounty_id));
+        Self::adjust_total_locked(&env, -release_amount);
+
+        // Transfer funds to contributor
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Calculate and collect fee if enabled
+        let fee_amount = Self::collect_release_fee(&env, &client, release_amount);
+        let net_amount = release_amount - fee_amount;
+
+        // Transfer net amount to contributor
+        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+
+        // Emit release event
+        // emit_funds_released(
+        //     &env,
+        //     FundsReleased {
+        //         bounty_id,
+        //         amount: net_amount, // Emit net amount (after fee)
+        //         recipient: contributor.clone(),
+        //         timestamp: env.ledger().timestamp(),
+        //     },
+        // );
+
+        // Emit release event
+        on_funds_released(
+            &env,
+            bounty_id,
+            net_amount,
+            &contributor,
+            escrow.remaining_amount,
+            false,
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("release"), duration);
+        Ok(())
+    }
+
+    /// Set the delay between `request_release` and `execute_release`, in
+    /// seconds (admin only). Applies to requests made after the change.
+    ///
+    /// While the delay is nonzero every release path (`release_funds`, the
+    /// batch releases and `execute_release`) needs a matured request for the
+    /// bounty and contributor being paid.
+    pub fn set_release_timelock(env: Env, delay: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let old = Self::get_release_timelock(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::ReleaseTimelock, &delay);
+        Self::record_config_change(&env, symbol_short!("rel_lock"), old, delay, &admin);
+        Ok(())
+    }
+
+    /// Get the release timelock in seconds (view function)
+    pub fn get_release_timelock(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReleaseTimelock)
+            .unwrap_or(0)
+    }
+
+    /// Request a timelocked release of a bounty to `contributor` (admin only).
+    ///
+    /// The request records who asked and when, and becomes executable via
+    /// `execute_release` once the release timelock has passed. A bounty holds
+    /// at most one request; requesting again replaces it and restarts the
+    /// timelock. The request is consumed by the release that pays it out and
+    /// dropped once the escrow is fully refunded. Emits `rel_req`.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Escrow has already been released or refunded
+    pub fn request_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<ReleaseRequest, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyReleased
+            && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        let request = ReleaseRequest {
+            bounty_id,
+            contributor,
+            requested_by: admin,
+            requested_at: now,
+            executable_after: now.saturating_add(Self::get_release_timelock(env.clone())),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseRequest(bounty_id), &request);
+
+        events::emit_release_requested(
+            &env,
+            events::ReleaseRequested {
+                bounty_id,
+                contributor: request.contributor.clone(),
+                requested_by: request.requested_by.clone(),
+                executable_after: request.executable_after,
+                timestamp: now,
+            },
+        );
+
+        Ok(request)
+    }
+
+    /// Get the pending release request for a bounty, if any (view function)
+    pub fn get_release_request(env: Env, bounty_id: u64) -> Option<ReleaseRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseRequest(bounty_id))
+    }
+
+    /// Execute a pending release request once its timelock has passed
+    /// (admin only). The release runs exactly like `release_funds` to the
+    /// requested contributor, which consumes the request.
+    ///
+    /// # Errors
+    /// * `ReleaseNotRequested` - No pending request for this bounty
+    /// * `ReleaseTimelocked` - `executable_after` has not been reached
+    /// * Any error returned by `release_funds`
+    pub fn execute_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let request =
+            Self::get_release_request(env.clone(), bounty_id).ok_or(Error::ReleaseNotRequested)?;
+        if env.ledger().timestamp() < request.executable_after {
+            return Err(Error::ReleaseTimelocked);
+        }
+
+        Self::release_funds(env, bounty_id, request.contributor)
+    }
+
+    /// Cancel a pending release request (admin only). Emits `rel_cncl`.
+    ///
+    /// # Errors
+    /// * `ReleaseNotRequested` - No pending request for this bounty
+    pub fn cancel_release_request(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let request =
+            Self::get_release_request(env.clone(), bounty_id).ok_or(Error::ReleaseNotRequested)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseRequest(bounty_id));
+
+        events::emit_release_request_cancelled(
+            &env,
+            events::ReleaseRequestCancelled {
+                bounty_id,
+                contributor: request.contributor,
+                cancelled_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Release a bounty and lock its successor in one transaction.
+    ///
+    /// For recurring bounties: pays out `bounty_id` to `contributor` exactly
+    /// like `release_funds`, then locks `new_bounty_id` funded by the same
+    /// depositor exactly like `lock_funds`. Requires both admin and depositor
+    /// authorization; if either step fails nothing is applied.
+    pub fn release_and_relock(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        new_bounty_id: u64,
+        new_amount: i128,
+        new_deadline: u64,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+
+        Self::release_funds(env.clone(), bounty_id, contributor)?;
+        Self::lock_funds(
+            env,
+            escrow.depositor,
+            new_bounty_id,
+            new_amount,
+            new_deadline,
+        )
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval.
+    /// The optional `reason` is recorded in the refund history when the approval is used.
+    ///
+    /// A bounty holds at most one approval. Approving again replaces the
+    /// outstanding one and emits `rf_repl` with both the overwritten and the
+    /// new approval, so concurrent operators can spot the conflict.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        Self::approve_refund_internal(env, bounty_id, amount, recipient, mode, reason, true)
+    }
+
+    /// Body of `approve_refund` (see `release_funds_internal` for the flag)
+    fn approve_refund_internal(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+        reason: Option<String>,
+        require_admin_auth: bool,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if require_admin_auth {
+            admin.require_auth();
+        }
+        Self::ensure_not_paused(&env)?;
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyRefunded
+            && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+            reason,
+        };
+
+        let previous: Option<RefundApproval> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id));
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        if let Some(previous) = previous {
+            events::emit_refund_approval_replaced(
+                &env,
+                events::RefundApprovalReplaced {
+                    bounty_id,
+                    previous,
+                    replacement: approval,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Set where a `Full` refund is sent once the deadline has passed
+    /// (depositor only).
+    /// Lets a depositor who will be offline delegate their refund to a chosen
+    /// address without involving the admin. Calling again replaces it.
+    pub fn set_refund_destination(
+        env: Env,
+        bounty_id: u64,
+        destination: Address,
+    ) -> Result<(), Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyRefunded
+            && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundDestination(bounty_id), &destination);
+
+        Ok(())
+    }
+
+    /// Get the depositor-chosen refund destination for a bounty, if any
+    pub fn get_refund_destination(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundDestination(bounty_id))
+    }
+
+    /// Hand a bounty's refund claim to a new depositor (current depositor only).
+    ///
+    /// Future refunds flow to `new_depositor`, who also takes over the
+    /// depositor's rights such as `set_refund_destination`. Any refund
+    /// destination chosen by the previous depositor is cleared, and a client
+    /// reference from `lock_funds_with_ref` moves to the new depositor.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow for `bounty_id`
+    /// * `ContractPaused` - Contract is paused
+    /// * `FundsNotLocked` - Escrow is not `Locked` or `PartiallyRefunded`
+    /// * `BountyFrozen` - Escrow is frozen
+    /// * `BountyExists` - `new_depositor` already uses the bounty's client reference
+    pub fn transfer_depositor(
+        env: Env,
+        bounty_id: u64,
+        new_depositor: Address,
+    ) -> Result<(), Error> {
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        escrow.depositor.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if Self::is_bounty_frozen_internal(&env, bounty_id) {
+            return Err(Error::BountyFrozen);
+        }
+
+        let client_ref: Option<(Address, BytesN<32>)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyClientRef(bounty_id));
+        if let Some((_, client_ref)) = client_ref.as_ref() {
+            if env.storage().persistent().has(&DataKey::ClientRef(
+                new_depositor.clone(),
+                client_ref.clone(),
+            )) {
+                return Err(Error::BountyExists);
+            }
+        }
+
+        let old_depositor = escrow.depositor.clone();
+        escrow.depositor = new_depositor.clone();
+        Self::save_escrow(&env, bounty_id, &mut escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundDestination(bounty_id));
+
+        // Re-key the client reference under the new depositor
+        if let Some((ref_owner, client_ref)) = client_ref {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ClientRef(ref_owner, client_ref.clone()));
+            env.storage().persistent().set(
+                &DataKey::ClientRef(new_depositor.clone(), client_ref.clone()),
+                &bounty_id,
+            );
+            env.storage().persistent().set(
+                &DataKey::BountyClientRef(bounty_id),
+                &(new_depositor.clone(), client_ref),
+            );
+        }
+
+        Self::adjust_depositor_escrow_count(&env, &old_depositor, -1);
+        Self::adjust_depositor_escrow_count(&env, &new_depositor, 1);
+        on_depositor_transferred(&env, bounty_id, &old_depositor, &new_depositor);
+
+        Ok(())
+    }
+
+    /// Refund funds with support for Full, Partial, and Custom refunds.
+    /// - Full: refunds all remaining funds to depositor
+    /// - Partial: refunds specified amount to depositor
+    /// - Custom: refunds specified amount to specified recipient
+    ///
+    /// Full and Partial refunds always go to the depositor; passing a
+    /// `recipient` with those modes is rejected with `InvalidAmount`.
+    /// The one exception is a `Full` refund after the deadline, which goes to
+    /// the destination set via `set_refund_destination` when there is one.
+    ///
+    /// Before the deadline every mode requires a matching admin approval
+    /// (same mode, amount and recipient); the approval is consumed on use.
+    ///
+    /// `reason` is stored in the refund history; when omitted for an approved
+    /// refund, the reason given at approval time is used.
+    ///
+    /// If the contract holds less than the refund amount (e.g. after an
+    /// emergency withdrawal), the refund is clamped to what backs this escrow
+    /// (the balance less other escrows' remaining amounts and accrued fees)
+    /// and a `rf_short` event reports the shortfall, which remains in
+    /// `remaining_amount`. `InsufficientFunds` is returned when nothing backs
+    /// the escrow at all.
+    ///
+    /// Returns `NothingToRefund` when the escrow has no remaining funds.
+    pub fn refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        let result = Self::refund_guarded(env.clone(), bounty_id, amount, recipient, mode, reason);
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        result
+    }
+
+    /// Body of `refund`, run while the reentrancy guard is held
+    fn refund_guarded(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let caller = escrow.depositor.clone();
+
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyRefunded
+            && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if escrow.remaining_amount == 0 {
+            return Err(Error::NothingToRefund);
+        }
+
+        if Self::is_bounty_frozen_internal(&env, bounty_id) {
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::BountyFrozen);
+        }
+
+        if !Self::is_escrow_active(&env, &escrow) {
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::NotYetActive);
+        }
+
+        // Verify deadline has passed
+        let now = env.ledger().timestamp();
+        let is_before_deadline = now < Self::refund_open_at(&env, escrow.deadline);
+
+        // Determine refund amount and recipient
+        let refund_amount: i128;
+        let refund_recipient: Address;
+        let mut refund_reason = reason;
+
+        // Only Custom refunds may redirect funds
+        if mode != RefundMode::Custom && recipient.is_some() {
+            return Err(Error::InvalidAmount);
+        }
+
+        match mode {
+            RefundMode::Full => {
+                refund_amount = escrow.remaining_amount;
+                refund_recipient = if is_before_deadline {
+                    escrow.depositor.clone()
+                } else {
+                    Self::get_refund_destination(env.clone(), bounty_id)
+                        .unwrap_or(escrow.depositor.clone())
+                };
+            }
+            RefundMode::Partial => {
+                refund_amount = amount.unwrap_or(escrow.remaining_amount);
+                refund_recipient = escrow.depositor.clone();
+            }
+            RefundMode::Custom => {
+                refund_amount = amount.ok_or(Error::InvalidAmount)?;
+                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
+            }
+        }
+
+        // Refunds before deadline require admin approval
+        if is_before_deadline {
+            let approval: Option<RefundApproval> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RefundApproval(bounty_id));
+
+            let approval = match approval {
+                Some(approval) => approval,
+                // Without an approval, depositor refunds simply aren't open yet
+                None if mode == RefundMode::Custom => return Err(Error::RefundNotApproved),
+                None => return Err(Error::DeadlineNotPassed),
+            };
+
+            // Verify approval matches request
+            if approval.amount != refund_amount
+                || approval.recipient != refund_recipient
+                || approval.mode != mode
+            {
+                return Err(Error::RefundNotApproved);
+            }
+
+            if refund_reason.is_none() {
+                refund_reason = approval.reason;
+            }
+
+            // Clear approval after use
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RefundApproval(bounty_id));
+        }
+
+        // Validate amount
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Transfer funds back to depositor
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Work out what actually backs this escrow: the contract balance less
+        // the other escrows' remaining amounts and the accrued fees
+        let contract_balance = client.balance(&env.current_contract_address());
+        let other_locked = Self::get_total_locked(env.clone()) - escrow.remaining_amount;
+        let accrued_fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AccruedFees)
+            .unwrap_or(0);
+        let available = contract_balance - other_locked - accrued_fees;
+        if available <= 0 {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // Pay out what backs the escrow rather than stranding the depositor;
+        // the shortfall stays in remaining_amount for a later refund
+        let mut refund_amount = refund_amount;
+        if available < refund_amount {
+            events::emit_refund_shortfall(
+                &env,
+                events::RefundShortfall {
+                    bounty_id,
+                    requested: refund_amount,
+                    refunded: available,
+                    shortfall: refund_amount - available,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+            refund_amount = available;
+        }
+
+        // Transfer funds
+        client.transfer(
+            &env.current_contract_address(),
+            &refund_recipient,
+            &refund_amount,
+        );
+
+        // Update escrow state
+        escrow.remaining_amount -= refund_amount;
+        Self::adjust_total_locked(&env, -refund_amount);
+
+        // Add to refund history
+        let refund_record = RefundRecord {
+            amount: refund_amount,
+            recipient: refund_recipient.clone(),
+            mode: mode.clone(),
+            timestamp: env.ledger().timestamp(),
+            reason: refund_reason,
+        };
+        Self::record_refund(&env, bounty_id, &mut escrow, refund_record);
+
+        // Update status
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        Self::save_escrow(&env, bounty_id, &mut escrow);
+        if escrow.status == EscrowStatus::Refunded {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseRequest(bounty_id));
+        }
+
+        // Emit refund event
+        // emit_funds_refunded(
+        //     &env,
+        //     FundsRefunded {
+        //         bounty_id,
+        //         amount: refund_amount,
+        //         refund_to: refund_recipient,
+        //         timestamp: env.ledger().timestamp(),
+        //         refund_mode: mode.clone(),
+        //         remaining_amount: escrow.remaining_amount,
+        //     },
+        // );
+
+        // Emit refund event
+        on_funds_refunded(
+            &env,
+            bounty_id,
+            refund_amount,
+            &refund_recipient,
+            escrow.remaining_amount,
+            mode,
+            &caller,
+        );
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+
+        Ok(())
+    }
+
+    /// Refund everything left in an escrow once its deadline has passed.
+    ///
+    /// Shorthand for a `Full` refund with no amount, recipient or reason, so
+    /// keepers can sweep expired bounties without building the arguments.
+    /// Anyone may call it; funds go to the depositor, or to the destination
+    /// set via `set_refund_destination`.
+    ///
+    /// # Returns
+    /// The amount actually refunded, which is less than the remaining amount
+    /// if the refund was clamped to the contract balance
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow for `bounty_id`
+    /// * `DeadlineNotPassed` - The refund window has not opened yet
+    /// * Any error `refund` returns for a `Full` refund
+    pub fn auto_refund(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+
+        // Never consume a pre-deadline approval meant for an explicit refund
+        if env.ledger().timestamp() < Self::refund_open_at(&env, escrow.deadline) {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        Self::refund(env.clone(), bounty_id, None, None, RefundMode::Full, None)?;
+
+        // `refund` may pay less than remaining when the contract is under-backed
+        let after = Self::get_escrow_info(env, bounty_id)?;
+        Ok(escrow.remaining_amount - after.remaining_amount)
+    }
+
+    /// Append a refund record to the escrow's history (internal helper).
+    /// Past the cap the record is only emitted, keeping the escrow entry bounded.
+    fn record_refund(env: &Env, bounty_id: u64, escrow: &mut Escrow, record: RefundRecord) {
+        if escrow.refund_history.len() < Self::get_max_refund_history(env.clone()) {
+            escrow.refund_history.push_back(record);
+        } else {
+            events::emit_refund_record_overflow(
+                env,
+                events::RefundRecordOverflow { bounty_id, record },
+            );
+        }
+    }
+
+    // ========================================================================
+    // View Functions (Read-only)
+    // ========================================================================
+
+    /// Retrieves escrow information for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Escrow)` - The complete escrow record
+    /// * `Err(Error::BountyNotFound)` - Bounty id was never used
+    /// * `Err(Error::BountyArchived)` - Bounty completed and was archived
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    ///
+    /// # Example
+    /// ```rust
+    /// let escrow_info = escrow_client.get_escrow_info(&42)?;
+    /// println!("Amount: {}", escrow_info.amount);
+    /// println!("Status: {:?}", escrow_info.status);
+    /// println!("Deadline: {}", escrow_info.deadline);
+    /// ```
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::ArchivedBountyId(bounty_id))
+            {
+                return Err(Error::BountyArchived);
+            }
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap())
+    }
+
+    /// Get an escrow's core fields without its refund history (view function).
+    ///
+    /// Prefer this over `get_escrow_info` when only status and amounts are
+    /// needed; the result stays small however many refunds were recorded.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    pub fn get_escrow_summary(env: Env, bounty_id: u64) -> Result<EscrowSummary, Error> {
+        let escrow = Self::get_escrow_info(env, bounty_id)?;
+        Ok(EscrowSummary {
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            remaining_amount: escrow.remaining_amount,
+            status: escrow.status,
+            deadline: escrow.deadline,
+        })
+    }
+
+    /// Preview what `release_funds` would pay out for a bounty right now (view function).
+    ///
+    /// Uses the same fee config and rounding as the release itself, so
+    /// `net` is exactly what the contributor would receive. With fee
+    /// accrual on, `fee_recipient` is the contract. Escrows with nothing
+    /// left to release report zeros.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `BountyArchived` - Bounty has been archived
+    pub fn preview_payout(env: Env, bounty_id: u64) -> Result<PayoutBreakdown, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        let gross = escrow.remaining_amount;
+        let (release_fee, fee_recipient) = Self::preview_release_fee(&env, gross);
+        Ok(PayoutBreakdown {
+            gross,
+            release_fee,
+            net: gross - release_fee,
+            fee_recipient,
+        })
+    }
+
+    /// Get an escrow together with its refund status in one call (view function).
+    ///
+    /// `has_valid_approval` is true when a stored refund approval does not
+    /// exceed the remaining amount. `is_refundable` additionally accounts for
+    /// status, freezes and `active_from`.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    pub fn get_bounty_summary(env: Env, bounty_id: u64) -> Result<BountySummary, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+
+        let deadline_passed =
+            env.ledger().timestamp() >= Self::refund_open_at(&env, escrow.deadline);
+
+        let approval: Option<RefundApproval> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id));
+        let has_valid_approval = approval
+            .map(|a| a.amount > 0 && a.amount <= escrow.remaining_amount)
+            .unwrap_or(false);
+
+        let status_allows_refund = escrow.status == EscrowStatus::Locked
+            || escrow.status == EscrowStatus::PartiallyRefunded
+            || escrow.status == EscrowStatus::PartiallyReleased;
+        let is_refundable = status_allows_refund
+            && escrow.remaining_amount > 0
+            && !Self::is_bounty_frozen_internal(&env, bounty_id)
+            && Self::is_escrow_active(&env, &escrow)
+            && (deadline_passed || has_valid_approval);
+
+        Ok(BountySummary {
+            bounty_id,
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            remaining_amount: escrow.remaining_amount,
+            status: escrow.status,
+            deadline: escrow.deadline,
+            active_from: escrow.active_from,
+            deadline_passed,
+            has_valid_approval,
+            is_refundable,
+        })
+    }
+
+    /// Returns the IDs of all bounties that released funds to a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `contributor` - The payout recipient to query
+    ///
+    /// # Returns
+    /// Bounty IDs in the order of their first payout; empty if none
+    pub fn get_bounties_by_contributor(env: Env, contributor: Address) -> Vec<u64> {
+        indexed::indexed_storage::get_contributor_bounty_ids(&env, &contributor)
+    }
+
+    /// Returns aggregated payout stats for a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `contributor` - The payout recipient to query
+    ///
+    /// # Returns
+    /// Number of distinct bounties, number of payouts and total amount received
+    pub fn get_contributor_stats(
+        env: Env,
+        contributor: Address,
+    ) -> indexed::indexed_storage::ContributorStats {
+        indexed::indexed_storage::get_contributor_stats(&env, &contributor)
+    }
+
+    /// Returns how many bounties are currently in `status`.
+    ///
+    /// Backed by counters maintained by the indexing hooks, so unlike
+    /// `get_bounty_stats` this is a single storage read.
+    pub fn count_by_status(env: Env, status: indexed::indexed_storage::BountyStatus) -> u32 {
+        indexed::indexed_storage::count_by_status(&env, &status)
+    }
+
+    /// Returns up to `limit` bounty IDs that still hold funds and whose
+    /// deadline is at or before `timestamp`, earliest deadline first.
+    ///
+    /// Backed by a deadline-ordered index, so a refund keeper can pull the
+    /// next batch of expired escrows without scanning every bounty id.
+    pub fn get_escrows_due_before(env: Env, timestamp: u64, limit: u32) -> Vec<u64> {
+        indexed::indexed_storage::get_bounties_due_before(&env, timestamp, limit)
+    }
+
+    /// Register `subscriber` for a `DeadlineApproaching` notification on a
+    /// bounty that still holds funds (subscriber only). Subscribing twice is a no-op.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow for `bounty_id`
+    /// * `FundsNotLocked` - The escrow is already settled
+    /// * `TooManySubscribers` - The bounty has `MAX_DEADLINE_SUBSCRIBERS` subscribers
+    pub fn subscribe_deadline_alerts(
+        env: Env,
+        bounty_id: u64,
+        subscriber: Address,
+    ) -> Result<(), Error> {
+        subscriber.require_auth();
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyRefunded
+            && escrow.status != EscrowStatus::PartiallyReleased
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let mut subscribers = Self::get_deadline_subscribers(env.clone(), bounty_id);
+        if subscribers.contains(&subscriber) {
+            return Ok(());
+        }
+        if subscribers.len() >= MAX_DEADLINE_SUBSCRIBERS {
+            return Err(Error::TooManySubscribers);
+        }
+        subscribers.push_back(subscriber);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DeadlineSubscribers(bounty_id), &subscribers);
+
+        Ok(())
+    }
+
+    /// Get the addresses subscribed to a bounty's deadline alerts
+    pub fn get_deadline_subscribers(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DeadlineSubscribers(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Emit a `DeadlineApproaching` notification to each subscriber of a
+    /// bounty whose deadline is within `DEADLINE_ALERT_WINDOW` (anyone may call).
+    ///
+    /// Alerts go out once per deadline: calling again is a no-op until the
+    /// deadline is extended. Outside the window, or for a settled escrow,
+    /// nothing is emitted.
+    ///
+    /// # Returns
+    /// The number of notifications emitted
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow for `bounty_id`
+    pub fn emit_due_notifications(env: Env, bounty_id: u64) -> Result<u32, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        let now = env.ledger().timestamp();
+        let open = escrow.status == EscrowStatus::Locked
+            || escrow.status == EscrowStatus::PartiallyRefunded
+            || escrow.status == EscrowStatus::PartiallyReleased;
+        let due =
+            now < escrow.deadline && now >= escrow.deadline.saturating_sub(DEADLINE_ALERT_WINDOW);
+        let sent_for: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DeadlineAlertSent(bounty_id));
+        if !open || !due || sent_for == Some(escrow.deadline) {
+            return Ok(0);
+        }
+
+        let subscribers = Self::get_deadline_subscribers(env.clone(), bounty_id);
+        for subscriber in subscribers.iter() {
+            indexed::_emit_notification(
+                &env,
+                indexed::NotificationEvent {
+                    notification_type: indexed::NotificationType::DeadlineApproaching,
+                    bounty_id,
+                    recipient: subscriber,
+                    message: String::from_str(&env, "Bounty deadline approaching"),
+                    timestamp: now,
+                    metadata: indexed::create_event_metadata(&env),
+                },
+            );
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::DeadlineAlertSent(bounty_id), &escrow.deadline);
+
+        Ok(subscribers.len())
+    }
+
+    /// Retrieves metadata for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Option<EscrowMetadata>)` - Metadata if set, None if not set
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    ///
+    /// # Example
+    /// ```rust
+    /// let metadata_opt = escrow_client.get_escrow_metadata(&42)?;
+    /// if let Some(metadata) = metadata_opt {
+    ///     println!("Repo: {:?}", metadata.repo_id);
+    ///     println!("Issue: {:?}", metadata.issue_id);
+    ///     println!("Type: {:?}", metadata.bounty_type);
+    ///     println!("Tags: {:?}", metadata.tags);
+    /// }
+    /// ```
+    pub fn get_escrow_metadata(env: Env, bounty_id: u64) -> Result<Option<EscrowMetadata>, Error> {
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get metadata if it exists
+        let metadata: Option<EscrowMetadata> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowMetadata(bounty_id));
+        Ok(metadata)
+    }
+
+    /// Retrieves complete escrow information including metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(EscrowWithMetadata)` - Combined escrow and metadata information
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    ///
+    /// # Gas Cost
+    /// Low - Two storage reads
+    ///
+    /// # Example
+    /// ```rust
+    /// let escrow_view = escrow_client.get_escrow_with_metadata(&42)?;
+    /// println!("Amount: {}", escrow_view.escrow.amount);
+    /// println!("Status: {:?}", escrow_view.escrow.status);
+    ///
+    /// if let Some(meta) = escrow_view.metadata {
+    ///     println!("Repository: {:?}", meta.repo_id);
+    ///     println!("Issue: {:?}", meta.issue_id);
+    /// }
+    /// ```
+    pub fn get_escrow_with_metadata(env: Env, bounty_id: u64) -> Result<EscrowWithMetadata, Error> {
+        // Get core escrow data
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+
+        // Get metadata if it exists
+        let metadata = Self::get_escrow_metadata(env, bounty_id)?;
+
+        Ok(EscrowWithMetadata { escrow, metadata })
+    }
+
+    /// Returns the current token balance held by the contract.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Current contract token balance
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    ///
+    /// # Use Cases
+    /// - Monitoring total locked funds
+    /// - Verifying contract solvency
+    /// - Auditing and reconciliation
+    ///
+    /// # Gas Cost
+    /// Low - Token contract call
+    ///
+    /// # Example
+    /// ```rust
+    /// let balance = escrow_client.get_balance()?;
+    /// println!("Total locked: {} stroops", balance);
+    /// ```
+    pub fn get_balance(env: Env) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
+        }
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Ok(client.balance(&env.current_contract_address()))
+    }
+
+    /// Returns the token contract address this escrow deployment uses.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Ok(Address)` - The configured token contract address
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    pub fn get_token(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Retrieves the refund history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - The refund history
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        Ok(escrow.refund_history)
+    }
+
+    /// Retrieves one page of the refund history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    /// * `page` - Zero-based page index
+    /// * `page_size` - Records per page
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - Records in the requested page, oldest first;
+    ///   empty past the end of the history
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history_paged(
+        env: Env,
+        bounty_id: u64,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<RefundRecord>, Error> {
+        let history = Self::get_refund_history(env.clone(), bounty_id)?;
+        let total_count = history.len();
+
+        let start = page.saturating_mul(page_size).min(total_count);
+        let end = start.saturating_add(page_size).min(total_count);
+
+        Ok(history.slice(start..end))
+    }
+
+    /// Gets refund eligibility information for a bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
+    ///   - can_refund: Whether refund is possible
+    ///   - deadline_passed: Whether the deadline plus the refund grace period has passed
+    ///   - remaining: Remaining amount in escrow
+    ///   - approval: Optional refund approval if exists
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_eligibility(
+        env: Env,
+        bounty_id: u64,
+    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        let now = env.ledger().timestamp();
+        let deadline_passed = now >= Self::refund_open_at(&env, escrow.deadline);
+
+        let approval = if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RefundApproval(bounty_id))
+        {
+            Some(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::RefundApproval(bounty_id))
+                    .unwrap(),
+            )
+        } else {
+            None
+        };
+
+        // can_refund is true if:
+        // 1. Status is Locked, PartiallyRefunded or PartiallyReleased AND
+        // 2. (deadline has passed OR there's an approval)
+        let can_refund = (escrow.status == EscrowStatus::Locked
+            || escrow.status == EscrowStatus::PartiallyRefunded
+            || escrow.status == EscrowStatus::PartiallyReleased)
+            && (deadline_passed || approval.is_some());
+
+        Ok((
+            can_refund,
+            deadline_passed,
+            escrow.remaining_amount,
+            approval,
+        ))
+    }
+
+    /// Extends the TTL of the contract's instance storage, which holds the
+    /// admin, token, fee, pause and other configuration keys.
+    ///
+    /// Anyone may call this; a contract left idle would otherwise lose its
+    /// configuration when the instance entry expires.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    pub fn bump_config_ttl(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Extends the storage TTL of a single escrow and its index entries.
+    ///
+    /// Anyone may call this; it only keeps existing state alive.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    pub fn bump_escrow_ttl(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !Self::bump_escrow_ttl_internal(&env, bounty_id) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(())
+    }
+
+    /// Extends the storage TTL of many escrows in one transaction.
+    ///
+    /// Missing bounty IDs are skipped silently so a keeper can pass a whole
+    /// cohort without pre-filtering.
+    ///
+    /// # Returns
+    /// Number of escrows whose TTL was extended
+    pub fn bump_escrows_ttl(env: Env, bounty_ids: Vec<u64>) -> u32 {
+        let mut bumped = 0u32;
+        for bounty_id in bounty_ids.iter() {
+            if Self::bump_escrow_ttl_internal(&env, bounty_id) {
+                bumped += 1;
+            }
+        }
+        bumped
+    }
+
+    /// Remove a settled escrow and its index entries to reclaim storage
+    /// (admin or depositor).
+    ///
+    /// Only `Released` or `Refunded` escrows with nothing remaining can be
+    /// archived, and only once the retention period has passed since their
+    /// last update. An `archived` event carries the final summary; the
+    /// refund history is dropped with the record. Contributor payout stats
+    /// are kept, and a small marker keeps the bounty id from being reused.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or the escrow's depositor (must authorize)
+    /// * `bounty_id` - Escrow to archive
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `BountyNotFound` - No escrow for `bounty_id`
+    /// * `Unauthorized` - `caller` is neither the admin nor the depositor
+    /// * `EscrowNotFinal` - Escrow is not fully released or refunded
+    /// * `RetentionNotElapsed` - Escrow settled too recently
+    pub fn archive_escrow(env: Env, caller: Address, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin && caller != escrow.depositor {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let is_final =
+            escrow.status == EscrowStatus::Released || escrow.status == EscrowStatus::Refunded;
+        if !is_final || escrow.remaining_amount != 0 {
+            return Err(Error::EscrowNotFinal);
+        }
+
+        let now = env.ledger().timestamp();
+        let retention = Self::get_archive_retention(env.clone());
+        if now < escrow.updated_at.saturating_add(retention) {
+            return Err(Error::RetentionNotElapsed);
+        }
+
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::Escrow(bounty_id));
+        storage.remove(&DataKey::RefundApproval(bounty_id));
+        storage.remove(&DataKey::ReleaseApprovals(bounty_id));
+        storage.remove(&DataKey::ReleaseRequest(bounty_id));
+        storage.remove(&DataKey::RefundDestination(bounty_id));
+        storage.remove(&DataKey::FrozenBounty(bounty_id));
+        storage.remove(&DataKey::DeadlineSubscribers(bounty_id));
+        storage.remove(&DataKey::DeadlineAlertSent(bounty_id));
+        storage.remove(&DataKey::BountyClientRef(bounty_id));
+        storage.remove(&DataKey::LockFeeAccrued(bounty_id));
+        storage.set(&DataKey::ArchivedBountyId(bounty_id), &true);
+        indexed::indexed_storage::remove_bounty(&env, bounty_id);
+
+        events::emit_escrow_archived(
+            &env,
+            events::EscrowArchived {
+                bounty_id,
+                depositor: escrow.depositor,
+                amount: escrow.amount,
+                final_status: escrow.status,
+                settled_at: escrow.updated_at,
+                archived_by: caller,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Whether `bounty_id` has a live escrow or belonged to an archived one
+    /// (internal helper)
+    fn is_bounty_id_taken(env: &Env, bounty_id: u64) -> bool {
+        let storage = env.storage().persistent();
+        storage.has(&DataKey::Escrow(bounty_id))
+            || storage.has(&DataKey::ArchivedBountyId(bounty_id))
+    }
+
+    /// Stamp the escrow with the current ledger and store it (internal helper).
+    /// Every escrow write goes through here so `updated_seq` tracks all changes.
+    fn save_escrow(env: &Env, bounty_id: u64, escrow: &mut Escrow) {
+        // A fully refunded escrow is final; never persist it as partially refunded
+        if escrow.status == EscrowStatus::PartiallyRefunded && escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        }
+        escrow.updated_at = env.ledger().timestamp();
+        escrow.updated_seq = env.ledger().sequence();
+        let key = DataKey::Escrow(bounty_id);
+
+        let observer: Option<Address> = env.storage().instance().get(&DataKey::StateObserver);
+        let old_status = observer.as_ref().and_then(|_| {
+            env.storage()
+                .persistent()
+                .get::<_, Escrow>(&key)
+                .map(|old| old.status)
+        });
+
+        env.storage().persistent().set(&key, escrow);
+
+        if let Some(observer) = observer {
+            if old_status.as_ref() != Some(&escrow.status) {
+                Self::notify_state_observer(
+                    env,
+                    &observer,
+                    bounty_id,
+                    old_status,
+                    escrow.status.clone(),
+                );
+            }
+        }
+    }
+
+    /// Best-effort `notify_transition` call to the state observer (internal
+    /// helper). A failing observer is ignored so it can never block the escrow.
+    fn notify_state_observer(
+        env: &Env,
+        observer: &Address,
+        bounty_id: u64,
+        old_status: Option<EscrowStatus>,
+        new_status: EscrowStatus,
+    ) {
+        let args: Vec<Val> = vec![
+            env,
+            bounty_id.into_val(env),
+            old_status.into_val(env),
+            new_status.into_val(env),
+        ];
+        let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            observer,
+            &Symbol::new(env, "notify_transition"),
+            args,
+        );
+    }
+
+    fn bump_escrow_ttl_internal(env: &Env, bounty_id: u64) -> bool {
+        let key = DataKey::Escrow(bounty_id);
+        if !env.storage().persistent().has(&key) {
+            return false;
+        }
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, ESCROW_TTL_THRESHOLD, ESCROW_TTL_EXTEND_TO);
+        indexed::indexed_storage::extend_bounty_ttl(
+            env,
+            bounty_id,
+            ESCROW_TTL_THRESHOLD,
+            ESCROW_TTL_EXTEND_TO,
+        );
+        true
+    }
+
+    /// Run a mixed list of admin operations with a single admin authorization.
+    ///
+    /// Each op behaves exactly like its entry point (`release_funds`,
+    /// `approve_refund`, `freeze_bounty`, `unfreeze_bounty`) and they run in
+    /// order. The batch is atomic: if any op fails, none take effect.
+    ///
+    /// # Returns
+    /// Number of operations executed
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `ops` is empty or larger than MAX_BATCH_SIZE
+    /// * Any error returned by one of the operations
+    pub fn batch_admin_ops(env: Env, ops: Vec<AdminOp>) -> Result<u32, Error> {
+        if ops.is_empty() || ops.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        for op in ops.iter() {
+            match op {
+                AdminOp::Release(bounty_id, contributor) => {
+                    Self::release_funds_internal(env.clone(), bounty_id, contributor, false)?
+                }
+                AdminOp::ApproveRefund(bounty_id, amount, recipient, mode, reason) => {
+                    Self::approve_refund_internal(
+                        env.clone(),
+                        bounty_id,
+                        amount,
+                        recipient,
+                        mode,
+                        reason,
+                        false,
+                    )?
+                }
+                AdminOp::Freeze(bounty_id) => {
+                    Self::freeze_bounty_internal(env.clone(), bounty_id, false)?
+                }
+                AdminOp::Unfreeze(bounty_id) => {
+                    Self::unfreeze_bounty_internal(env.clone(), bounty_id, false)?
+                }
+            }
+        }
+
+        Ok(ops.len())
+    }
+
+    /// Batch lock funds for multiple bounties in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
+    ///
+    /// # Arguments
+    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, and deadline
+    ///
+    /// # Returns
+    /// Number of successfully locked bounties
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * BountyExists - if any bounty_id already exists
+    /// * InvalidAmount - if any amount is below the minimum or the total overflows
+    /// * InvalidDeadline - if any deadline is not in the future or breaks the deadline policy
+    /// * NotInitialized - if contract is not initialized
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    /// Each item is charged the lock fee exactly like `lock_funds`, matching
+    /// `batch_release_funds`, which charges the release fee per item.
+    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
+        // Validate batch size
+        let batch_size = items.len();
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize);
+        }
+        if batch_size > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if Self::is_sunset(env.clone()) {
+            return Err(Error::LocksDisabled);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let timestamp = env.ledger().timestamp();
+
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            // Check if bounty already exists
+            if Self::is_bounty_id_taken(&env, item.bounty_id) {
+                return Err(Error::BountyExists);
+            }
+
+            // Validate amount
+            if item.amount < MIN_LOCK_AMOUNT {
+                return Err(Error::InvalidAmount);
+            }
+            total_amount = total_amount
+                .checked_add(item.amount)
+                .ok_or(Error::InvalidAmount)?;
+
+            // Validate deadline (same rule as lock_funds)
+            if !Self::is_valid_deadline(&env, item.deadline) {
+                return Err(Error::InvalidDeadline);
+            }
+
+            // Check for duplicate bounty_ids in the batch
+            let mut count = 0u32;
+            for other_item in items.iter() {
+                if other_item.bounty_id == item.bounty_id {
+                    count += 1;
+                }
+            }
+            if count > 1 {
+                return Err(Error::DuplicateBountyId);
+            }
+        }
+
+        // High-value deposits also need the admin's co-signature
+        if items
+            .iter()
+            .any(|item| Self::requires_admin_cosign(&env, item.amount))
+        {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
+
+        // Collect unique depositors and require auth once for each
+        // This prevents "frame is already authorized" errors when same depositor appears multiple times
+        let mut seen_depositors: Vec<Address> = Vec::new(&env);
+        for item in items.iter() {
+            let mut found = false;
+            for seen in seen_depositors.iter() {
+                if seen.clone() == item.depositor {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                seen_depositors.push_back(item.depositor.clone());
+                item.depositor.require_auth();
+            }
+        }
+
+        // Process all items (atomic - all succeed or all fail)
+        let mut locked_count = 0u32;
+        for item in items.iter() {
+            // Transfer funds in, collecting the lock fee as lock_funds does
+            let fee_amount =
+                Self::take_deposit(&env, &client, item.bounty_id, &item.depositor, item.amount)?;
+            let net_amount = item.amount - fee_amount;
+
+            // Create escrow record
+            let mut escrow = Escrow {
+                depositor: item.depositor.clone(),
+                amount: net_amount,
+                status: EscrowStatus::Locked,
+                deadline: item.deadline,
+                refund_history: vec![&env],
+                remaining_amount: net_amount,
+                active_from: 0,
+                gross_amount: item.amount,
+                lock_fee_paid: fee_amount,
+                updated_at: 0,
+                updated_seq: 0,
+            };
+
+            // Store escrow
+            Self::save_escrow(&env, item.bounty_id, &mut escrow);
+            Self::adjust_total_locked(&env, net_amount);
+            Self::count_new_escrow(&env, &item.depositor);
+
+            // Emit individual event for each locked bounty
+            // emit_funds_locked(
+            //     &env,
+            //     FundsLocked {
+            //         bounty_id: item.bounty_id,
+            //         amount: item.amount,
+            //         depositor: item.depositor.clone(),
+            //         deadline: item.deadline,
+            //     },
+            // );
+
+            // Emit individual event for each locked bounty
+            on_funds_locked(
+                &env,
+                item.bounty_id,
+                item.amount,
+                &item.depositor,
+                item.deadline,
+            );
+
+            locked_count += 1;
+        }
+
+        // Emit batch event
+        emit_batch_funds_locked(
+            &env,
+            BatchFundsLocked {
+                count: locked_count,
+                total_amount,
+                timestamp,
+            },
+        );
+
+        Ok(locked_count)
+    }
+
+    /// Batch lock funds and return the locked bounty IDs.
+    ///
+    /// Same validation and atomicity as `batch_lock_funds`; on success the
+    /// returned IDs are the input IDs in order.
     ///
     /// # Returns
-    /// * `Ok(EscrowWithMetadata)` - Combined escrow and metadata information
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// Bounty IDs that were locked, in input order
+    pub fn batch_lock_funds_detailed(
+        env: Env,
+        items: Vec<LockFundsItem>,
+    ) -> Result<Vec<u64>, Error> {
+        let mut bounty_ids = Vec::new(&env);
+        for item in items.iter() {
+            bounty_ids.push_back(item.bounty_id);
+        }
+
+        Self::batch_lock_funds(env, items)?;
+
+        Ok(bounty_ids)
+    }
+
+    /// Batch release funds to multiple contributors in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
     ///
-    /// # Gas Cost
-    /// Low - Two storage reads
+    /// # Arguments
+    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
     ///
-    /// # Example
-    /// ```rust
-    /// let escrow_view = escrow_client.get_escrow_with_metadata(&42)?;
-    /// println!("Amount: {}", escrow_view.escrow.amount);
-    /// println!("Status: {:?}", escrow_view.escrow.status);
+    /// # Returns
+    /// Number of successfully released bounties
     ///
-    /// if let Some(meta) = escrow_view.metadata {
-    ///     println!("Repository: {:?}", meta.repo_id);
-    ///     println!("Issue: {:?}", meta.issue_id);
-    /// }
-    /// ```
-    pub fn get_escrow_with_metadata(env: Env, bounty_id: u64) -> Result<EscrowWithMetadata, Error> {
-        // Get core escrow data
-        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * BountyNotFound - if any bounty_id doesn't exist
+    /// * FundsNotLocked - if any bounty is not in Locked status
+    /// * Unauthorized - if caller is not admin
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
+        Ok(Self::batch_release(env, items)?.len())
+    }
+
+    /// Batch release funds and return what each contributor received.
+    ///
+    /// Same validation and atomicity as `batch_release_funds`. The amount
+    /// reported per bounty is net of the release fee, i.e. exactly what was
+    /// transferred to its contributor.
+    ///
+    /// # Returns
+    /// `(bounty_id, net_amount)` pairs, in input order
+    pub fn batch_release_funds_detailed(
+        env: Env,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<Vec<(u64, i128)>, Error> {
+        Self::batch_release(env, items)
+    }
+
+    /// Shared body of the batch release entry points (internal helper)
+    fn batch_release(env: Env, items: Vec<ReleaseFundsItem>) -> Result<Vec<(u64, i128)>, Error> {
+        // Validate batch size
+        let batch_size = items.len();
+        if batch_size == 0 {
+            return Err(Error::InvalidBatchSize);
+        }
+        if batch_size > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let timestamp = env.ledger().timestamp();
+
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            // Check if bounty exists
+            if !env
+                .storage()
+                .persistent()
+                .has(&DataKey::Escrow(item.bounty_id))
+            {
+                return Err(Error::BountyNotFound);
+            }
+
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+
+            // Check the escrow still holds funds
+            if escrow.status != EscrowStatus::Locked
+                && escrow.status != EscrowStatus::PartiallyReleased
+                && escrow.status != EscrowStatus::PartiallyRefunded
+            {
+                return Err(Error::FundsNotLocked);
+            }
+
+            if Self::is_bounty_frozen_internal(&env, item.bounty_id) {
+                return Err(Error::BountyFrozen);
+            }
+
+            if !Self::is_escrow_active(&env, &escrow) {
+                return Err(Error::NotYetActive);
+            }
+
+            Self::check_release_approvals(&env, item.bounty_id)?;
+            Self::check_recipient(&env, &escrow, &item.contributor)?;
+            Self::check_release_request(&env, item.bounty_id, &item.contributor)?;
+
+            // Conservation guard: never pay out more than the escrow still holds
+            if escrow.remaining_amount <= 0 || escrow.remaining_amount > escrow.amount {
+                return Err(Error::InsufficientFunds);
+            }
+
+            // Check for duplicate bounty_ids in the batch
+            let mut count = 0u32;
+            for other_item in items.iter() {
+                if other_item.bounty_id == item.bounty_id {
+                    count += 1;
+                }
+            }
+            if count > 1 {
+                return Err(Error::DuplicateBountyId);
+            }
+
+            total_amount = total_amount
+                .checked_add(escrow.remaining_amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+
+        // Process all items (atomic - all succeed or all fail)
+        let mut released = Vec::new(&env);
+        for item in items.iter() {
+            let mut escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+
+            // Transfer the live balance, net of the release fee, to contributor
+            let release_amount = escrow.remaining_amount;
+            let fee_amount = Self::collect_release_fee(&env, &client, release_amount);
+            let net_amount = release_amount - fee_amount;
+            client.transfer(&contract_address, &item.contributor, &net_amount);
+
+            // Update escrow status
+            escrow.status = EscrowStatus::Released;
+            escrow.remaining_amount = 0;
+            Self::save_escrow(&env, item.bounty_id, &mut escrow);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseApprovals(item.bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseRequest(item.bounty_id));
+            Self::adjust_total_locked(&env, -release_amount);
+
+            // Emit individual event for each released bounty
+            // emit_funds_released(
+            //     &env,
+            //     FundsReleased {
+            //         bounty_id: item.bounty_id,
+            //         amount: escrow.amount,
+            //         recipient: item.contributor.clone(),
+            //         timestamp,
+            //     },
+            // );
 
-        // Get metadata if it exists
-        let metadata = Self::get_escrow_metadata(env, bounty_id)?;
+            // Emit individual event for each released bounty
+            on_funds_released(
+                &env,
+                item.bounty_id,
+                net_amount,
+                &item.contributor,
+                escrow.remaining_amount,
+                false,
+            );
 
-        Ok(EscrowWithMetadata { escrow, metadata })
+            released.push_back((item.bounty_id, net_amount));
+        }
+
+        // Emit batch event
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count: released.len(),
+                total_amount,
+                timestamp,
+            },
+        );
+
+        Ok(released)
     }
 
-    /// Returns the current token balance held by the contract.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    ///
-    /// # Returns
-    /// * `Ok(i128)` - Current contract token balance
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// Release funds for a batch of bounties, skipping items that can't be released.
     ///
-    /// # Use Cases
-    /// - Monitoring total locked funds
-    /// - Verifying contract solvency
-    /// - Auditing and reconciliation
+    /// Unlike `batch_release_funds`, one invalid item does not abort the batch:
+    /// every releasable item is paid out and each skipped item is reported with
+    /// its error code. Items are checked in order against the live state, so a
+    /// repeated bounty id is skipped as `FundsNotLocked`; a payout the token
+    /// rejects is skipped as `TransferFailed`. Release fees apply as in
+    /// `release_funds`, and `total_amount` is the net paid to contributors.
     ///
-    /// # Gas Cost
-    /// Low - Token contract call
+    /// Since the call itself succeeds, each skipped item also emits an
+    /// `ErrorOccurred` event (unless event verbosity is minimal), so rejected
+    /// releases are observable on-ledger.
     ///
-    /// # Example
-    /// ```rust
-    /// let balance = escrow_client.get_balance()?;
-    /// println!("Total locked: {} stroops", balance);
-    /// ```
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
-        if !env.storage().instance().has(&DataKey::Token) {
+    /// # Errors
+    /// Batch-level problems still fail the whole call:
+    /// * `InvalidBatchSize` - Empty batch or more than `MAX_BATCH_SIZE` items
+    /// * `ContractPaused` - Contract is paused
+    /// * `NotInitialized` - Contract not initialized
+    pub fn batch_release_best_effort(
+        env: Env,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<BatchReleaseResult, Error> {
+        let batch_size = items.len();
+        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        Ok(client.balance(&env.current_contract_address()))
-    }
+        let contract_address = env.current_contract_address();
 
-    /// Retrieves the refund history for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok(Vec<RefundRecord>)` - The refund history
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        let mut result = BatchReleaseResult {
+            successful: Vec::new(&env),
+            failed: Vec::new(&env),
+            total_amount: 0,
+        };
+
+        for (index, item) in items.iter().enumerate() {
+            let releasable = Self::releasable_escrow(&env, item.bounty_id).and_then(|escrow| {
+                Self::check_recipient(&env, &escrow, &item.contributor)?;
+                Self::check_release_request(&env, item.bounty_id, &item.contributor)?;
+                Ok(escrow)
+            });
+            let mut escrow = match releasable {
+                Ok(escrow) => escrow,
+                Err(err) => {
+                    on_operation_failed(&env, &err, Some(item.bounty_id), &admin);
+                    result.failed.push_back(BatchItemError {
+                        index: index as u32,
+                        bounty_id: item.bounty_id,
+                        error_code: err as u32,
+                    });
+                    continue;
+                }
+            };
+
+            // Settle state before paying out (checks-effects-interactions)
+            let unsettled = escrow.clone();
+            let approvals: Option<Vec<Address>> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReleaseApprovals(item.bounty_id));
+            let request = Self::get_release_request(env.clone(), item.bounty_id);
+            let release_amount = escrow.remaining_amount;
+            escrow.status = EscrowStatus::Released;
+            escrow.remaining_amount = 0;
+            Self::save_escrow(&env, item.bounty_id, &mut escrow);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseApprovals(item.bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseRequest(item.bounty_id));
+            Self::adjust_total_locked(&env, -release_amount);
+
+            let (fee_amount, _) = Self::preview_release_fee(&env, release_amount);
+            let net_amount = release_amount - fee_amount;
+            let paid = matches!(
+                client.try_transfer(&contract_address, &item.contributor, &net_amount),
+                Ok(Ok(()))
+            );
+            if !paid {
+                // Put the escrow back as it was and report the item
+                let mut unsettled = unsettled;
+                Self::save_escrow(&env, item.bounty_id, &mut unsettled);
+                if let Some(approvals) = approvals {
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::ReleaseApprovals(item.bounty_id), &approvals);
+                }
+                if let Some(request) = request {
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::ReleaseRequest(item.bounty_id), &request);
+                }
+                Self::adjust_total_locked(&env, release_amount);
+                on_operation_failed(&env, &Error::TransferFailed, Some(item.bounty_id), &admin);
+                result.failed.push_back(BatchItemError {
+                    index: index as u32,
+                    bounty_id: item.bounty_id,
+                    error_code: Error::TransferFailed as u32,
+                });
+                continue;
+            }
+            // Only take the fee once the payout went through
+            Self::collect_release_fee(&env, &client, release_amount);
+
+            on_funds_released(
+                &env,
+                item.bounty_id,
+                net_amount,
+                &item.contributor,
+                escrow.remaining_amount,
+                false,
+            );
+
+            result.successful.push_back(item.bounty_id);
+            result.total_amount = result.total_amount.saturating_add(net_amount);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        Ok(escrow.refund_history)
-    }
 
-    /// Gets refund eligibility information for a bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
-    ///   - can_refund: Whether refund is possible
-    ///   - deadline_passed: Whether the deadline has passed
-    ///   - remaining: Remaining amount in escrow
-    ///   - approval: Optional refund approval if exists
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_eligibility(
-        env: Env,
-        bounty_id: u64,
-    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if !result.successful.is_empty() {
+            emit_batch_funds_released(
+                &env,
+                BatchFundsReleased {
+                    count: result.successful.len(),
+                    total_amount: result.total_amount,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
         }
+
+        Ok(result)
+    }
+
+    /// Load an escrow and check it can be fully released (internal helper)
+    fn releasable_escrow(env: &Env, bounty_id: u64) -> Result<Escrow, Error> {
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
 
-        let now = env.ledger().timestamp();
-        let deadline_passed = now >= escrow.deadline;
-
-        let approval = if env
-            .storage()
-            .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
+        if escrow.status != EscrowStatus::Locked
+            && escrow.status != EscrowStatus::PartiallyReleased
+            && escrow.status != EscrowStatus::PartiallyRefunded
         {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
-        };
+            return Err(Error::FundsNotLocked);
+        }
 
-        // can_refund is true if:
-        // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
-        let can_refund = (escrow.status == EscrowStatus::Locked
-            || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+        if Self::is_bounty_frozen_internal(env, bounty_id) {
+            return Err(Error::BountyFrozen);
+        }
 
-        Ok((
-            can_refund,
-            deadline_passed,
-            escrow.remaining_amount,
-            approval,
-        ))
+        if !Self::is_escrow_active(env, &escrow) {
+            return Err(Error::NotYetActive);
+        }
+
+        Self::check_release_approvals(env, bounty_id)?;
+
+        // Conservation guard: never pay out more than the escrow still holds
+        if escrow.remaining_amount <= 0 || escrow.remaining_amount > escrow.amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        Ok(escrow)
     }
 
-    /// Batch lock funds for multiple bounties in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
+    /// Batch release partial amounts to multiple contributors in a single transaction.
+    /// Unlike `batch_release_funds`, each item specifies how much of the escrow to release,
+    /// so milestone-based programs can pay out a portion of many bounties at once.
     ///
     /// # Arguments
-    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, and deadline
+    /// * `items` - Vector of (bounty_id, contributor, amount) tuples
     ///
     /// # Returns
-    /// Number of successfully locked bounties
+    /// Number of bounties that received a partial release
     ///
     /// # Errors
     /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
-    /// * BountyExists - if any bounty_id already exists
-    /// * NotInitialized - if contract is not initialized
+    /// * BountyNotFound - if any bounty_id doesn't exist
+    /// * FundsNotLocked - if any bounty is not Locked or PartiallyReleased
+    /// * InvalidAmount - if any amount is not positive or exceeds the remaining amount
+    /// * DuplicateBountyId - if a bounty_id appears more than once in the batch
+    ///
+    /// # State Changes
+    /// - Decrements each escrow's `remaining_amount` by the released amount
+    /// - Sets status to PartiallyReleased, or Released once `remaining_amount` reaches zero
+    /// - Charges the release fee on each released amount, not the full escrow
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
-    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
+    pub fn batch_release_partial(env: Env, items: Vec<(u64, Address, i128)>) -> Result<u32, Error> {
         // Validate batch size
         let batch_size = items.len();
         if batch_size == 0 {
@@ -1919,131 +5645,149 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
         let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
         // Validate all items before processing (all-or-nothing approach)
-        for item in items.iter() {
-            // Check if bounty already exists
-            if env
+        let mut total_amount: i128 = 0;
+        for (bounty_id, contributor, amount) in items.iter() {
+            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                return Err(Error::BountyNotFound);
+            }
+
+            let escrow: Escrow = env
                 .storage()
                 .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap();
+
+            if escrow.status != EscrowStatus::Locked
+                && escrow.status != EscrowStatus::PartiallyReleased
             {
-                return Err(Error::BountyExists);
+                return Err(Error::FundsNotLocked);
             }
 
-            // Validate amount
-            if item.amount <= 0 {
+            if Self::is_bounty_frozen_internal(&env, bounty_id) {
+                return Err(Error::BountyFrozen);
+            }
+
+            if !Self::is_escrow_active(&env, &escrow) {
+                return Err(Error::NotYetActive);
+            }
+
+            Self::check_release_approvals(&env, bounty_id)?;
+            Self::check_recipient(&env, &escrow, &contributor)?;
+            Self::check_release_request(&env, bounty_id, &contributor)?;
+
+            if amount <= 0 || amount > escrow.remaining_amount {
                 return Err(Error::InvalidAmount);
             }
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
+            for (other_id, _, _) in items.iter() {
+                if other_id == bounty_id {
                     count += 1;
                 }
             }
             if count > 1 {
                 return Err(Error::DuplicateBountyId);
             }
-        }
 
-        // Collect unique depositors and require auth once for each
-        // This prevents "frame is already authorized" errors when same depositor appears multiple times
-        let mut seen_depositors: Vec<Address> = Vec::new(&env);
-        for item in items.iter() {
-            let mut found = false;
-            for seen in seen_depositors.iter() {
-                if seen.clone() == item.depositor {
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                seen_depositors.push_back(item.depositor.clone());
-                item.depositor.require_auth();
-            }
+            total_amount = total_amount
+                .checked_add(amount)
+                .ok_or(Error::InvalidAmount)?;
         }
 
         // Process all items (atomic - all succeed or all fail)
-        let mut locked_count = 0u32;
-        for item in items.iter() {
-            // Transfer funds from depositor to contract
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+        let mut released_count = 0u32;
+        for (bounty_id, contributor, amount) in items.iter() {
+            let mut escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap();
 
-            // Create escrow record
-            let escrow = Escrow {
-                depositor: item.depositor.clone(),
-                amount: item.amount,
-                status: EscrowStatus::Locked,
-                deadline: item.deadline,
-                refund_history: vec![&env],
-                remaining_amount: item.amount,
+            // Update escrow state before transferring
+            escrow.remaining_amount -= amount;
+            escrow.status = if escrow.remaining_amount == 0 {
+                EscrowStatus::Released
+            } else {
+                EscrowStatus::PartiallyReleased
             };
-
-            // Store escrow
+            Self::save_escrow(&env, bounty_id, &mut escrow);
             env.storage()
                 .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+                .remove(&DataKey::ReleaseApprovals(bounty_id));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ReleaseRequest(bounty_id));
+            Self::adjust_total_locked(&env, -amount);
 
-            // Emit individual event for each locked bounty
-            // emit_funds_locked(
-            //     &env,
-            //     FundsLocked {
-            //         bounty_id: item.bounty_id,
-            //         amount: item.amount,
-            //         depositor: item.depositor.clone(),
-            //         deadline: item.deadline,
-            //     },
-            // );
+            // The release fee applies to the released portion only
+            let fee_amount = Self::collect_release_fee(&env, &client, amount);
+            let net_amount = amount - fee_amount;
 
-            // Emit individual event for each locked bounty
-            on_funds_locked(
-                &env,
-                item.bounty_id,
-                item.amount,
-                &item.depositor,
-                item.deadline,
+            // Transfer the partial amount to contributor
+            client.transfer(&contract_address, &contributor, &net_amount);
+
+            // Emit individual event for each partial release
+            on_funds_released(
+                &env,
+                bounty_id,
+                net_amount,
+                &contributor,
+                escrow.remaining_amount,
+                escrow.remaining_amount > 0,
             );
 
-            locked_count += 1;
+            released_count += 1;
         }
 
         // Emit batch event
-        emit_batch_funds_locked(
+        emit_batch_funds_released(
             &env,
-            BatchFundsLocked {
-                count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
+            BatchFundsReleased {
+                count: released_count,
+                total_amount,
                 timestamp,
             },
         );
 
-        Ok(locked_count)
+        Ok(released_count)
     }
 
-    /// Batch release funds to multiple contributors in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
+    /// Batch increase the escrowed amount of multiple bounties in a single transaction.
+    /// Useful for sponsors topping up many bounties at once (e.g. a program-wide bonus).
     ///
     /// # Arguments
-    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
+    /// * `depositor` - Address that owns every bounty in the batch (must authorize)
+    /// * `items` - Vector of (bounty_id, additional_amount) pairs
     ///
     /// # Returns
-    /// Number of successfully released bounties
+    /// Number of bounties whose amount was increased
     ///
     /// # Errors
     /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
     /// * BountyNotFound - if any bounty_id doesn't exist
+    /// * Unauthorized - if any bounty was not locked by `depositor`
     /// * FundsNotLocked - if any bounty is not in Locked status
-    /// * Unauthorized - if caller is not admin
+    /// * InvalidAmount - if any additional amount is not positive or the total overflows
+    /// * DuplicateBountyId - if a bounty_id appears more than once in the batch
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
-    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
+    /// The total of all increases is transferred from the depositor in a single transfer.
+    pub fn batch_increase_amount(
+        env: Env,
+        depositor: Address,
+        items: Vec<(u64, i128)>,
+    ) -> Result<u32, Error> {
         // Validate batch size
         let batch_size = items.len();
         if batch_size == 0 {
@@ -2062,41 +5806,37 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
+        depositor.require_auth();
 
         // Validate all items before processing (all-or-nothing approach)
         let mut total_amount: i128 = 0;
-        for item in items.iter() {
-            // Check if bounty exists
-            if !env
-                .storage()
-                .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
-            {
+        for (bounty_id, additional) in items.iter() {
+            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
                 return Err(Error::BountyNotFound);
             }
 
             let escrow: Escrow = env
                 .storage()
                 .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
+                .get(&DataKey::Escrow(bounty_id))
                 .unwrap();
 
-            // Check if funds are locked
+            if escrow.depositor != depositor {
+                return Err(Error::Unauthorized);
+            }
+
             if escrow.status != EscrowStatus::Locked {
                 return Err(Error::FundsNotLocked);
             }
 
+            if additional <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
+            for (other_id, _) in items.iter() {
+                if other_id == bounty_id {
                     count += 1;
                 }
             }
@@ -2104,64 +5844,346 @@ impl BountyEscrowContract {
                 return Err(Error::DuplicateBountyId);
             }
 
+            // Reject overflow here, before any tokens move
+            for current in [escrow.amount, escrow.remaining_amount, escrow.gross_amount] {
+                current
+                    .checked_add(additional)
+                    .ok_or(Error::InvalidAmount)?;
+            }
             total_amount = total_amount
-                .checked_add(escrow.amount)
+                .checked_add(additional)
                 .ok_or(Error::InvalidAmount)?;
         }
 
+        // Transfer the combined top-up from depositor to contract
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::transfer_in(
+            &client,
+            &depositor,
+            &env.current_contract_address(),
+            total_amount,
+        )?;
+        Self::adjust_total_locked(&env, total_amount);
+
         // Process all items (atomic - all succeed or all fail)
-        let mut released_count = 0u32;
-        for item in items.iter() {
+        let mut increased_count = 0u32;
+        for (bounty_id, additional) in items.iter() {
             let mut escrow: Escrow = env
                 .storage()
                 .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
+                .get(&DataKey::Escrow(bounty_id))
                 .unwrap();
 
-            // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+            let old_amount = escrow.amount;
+            escrow.amount = escrow
+                .amount
+                .checked_add(additional)
+                .ok_or(Error::InvalidAmount)?;
+            escrow.remaining_amount = escrow
+                .remaining_amount
+                .checked_add(additional)
+                .ok_or(Error::InvalidAmount)?;
+            escrow.gross_amount = escrow
+                .gross_amount
+                .checked_add(additional)
+                .ok_or(Error::InvalidAmount)?;
 
-            // Update escrow status
-            escrow.status = EscrowStatus::Released;
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            Self::save_escrow(&env, bounty_id, &mut escrow);
 
-            // Emit individual event for each released bounty
-            // emit_funds_released(
-            //     &env,
-            //     FundsReleased {
-            //         bounty_id: item.bounty_id,
-            //         amount: escrow.amount,
-            //         recipient: item.contributor.clone(),
-            //         timestamp,
-            //     },
-            // );
+            // Emit individual event for each increased bounty
+            _on_amount_increased(&env, bounty_id, old_amount, additional, &depositor);
 
-            // Emit individual event for each released bounty
-            on_funds_released(
-                &env,
-                item.bounty_id,
-                escrow.amount,
-                &item.contributor,
-                escrow.remaining_amount,
-                false,
-            );
+            increased_count += 1;
+        }
 
-            released_count += 1;
+        Ok(increased_count)
+    }
+}
+
+/// Fee entry points, only exported when the `fees` feature is enabled.
+/// They live in their own `#[contractimpl]` block because the generated
+/// client and test registrations don't honour `cfg` on individual methods.
+#[cfg(feature = "fees")]
+#[contractimpl]
+impl BountyEscrowContract {
+    /// Update fee configuration (admin only)
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+        rounding_mode: Option<FeeRoundingMode>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Emit batch event
-        emit_batch_funds_released(
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let old_config = Self::get_fee_config_internal(&env);
+        let mut fee_config = old_config.clone();
+
+        if let Some(rate) = lock_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+            fee_config.lock_fee_rate = rate;
+        }
+
+        if let Some(rate) = release_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+            fee_config.release_fee_rate = rate;
+        }
+
+        // Each rate may be in bounds while the pair still stacks too high
+        if fee_config.lock_fee_rate + fee_config.release_fee_rate > MAX_COMBINED_FEE_RATE {
+            return Err(Error::InvalidFeeRate);
+        }
+
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
+        }
+
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
+
+        if let Some(mode) = rounding_mode {
+            fee_config.rounding_mode = mode;
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeConfig, &fee_config);
+        Self::record_config_change(
             &env,
-            BatchFundsReleased {
-                count: released_count,
-                total_amount,
-                timestamp,
+            symbol_short!("fee_cfg"),
+            old_config,
+            fee_config.clone(),
+            &admin,
+        );
+
+        events::emit_fee_config_updated(
+            &env,
+            events::FeeConfigUpdated {
+                lock_fee_rate: fee_config.lock_fee_rate,
+                release_fee_rate: fee_config.release_fee_rate,
+                fee_recipient: fee_config.fee_recipient.clone(),
+                fee_enabled: fee_config.fee_enabled,
+                rounding_mode: fee_config.rounding_mode,
+                timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(released_count)
+        Ok(())
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Enable or disable fee accrual (admin only)
+    ///
+    /// When enabled, lock and release fees are kept in the contract and
+    /// added to `accrued_fees` instead of being transferred to the fee
+    /// recipient on every operation. Use `withdraw_accrued_fees` to sweep them.
+    pub fn set_fee_accrual(env: Env, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let old = Self::is_fee_accrual_enabled(&env);
+        env.storage().instance().set(&DataKey::FeeAccrual, &enabled);
+        Self::record_config_change(&env, symbol_short!("fee_accr"), old, enabled, &admin);
+        Ok(())
+    }
+
+    /// Get fees accrued in the contract and not yet withdrawn (view function)
+    pub fn get_accrued_fees(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AccruedFees)
+            .unwrap_or(0)
+    }
+
+    /// Withdraw accrued fees to `to`.
+    ///
+    /// The fee recipient may withdraw to itself; any other destination
+    /// requires admin authorization. The withdrawal is capped so the
+    /// contract balance never drops below the outstanding escrow total.
+    ///
+    /// # Returns
+    /// The amount transferred
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `InsufficientFunds` - Fees are accrued but the balance backing them is gone
+    pub fn withdraw_accrued_fees(env: Env, to: Address) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let fee_config = Self::get_fee_config_internal(&env);
+        if to == fee_config.fee_recipient {
+            fee_config.fee_recipient.require_auth();
+        } else {
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            admin.require_auth();
+        }
+        Self::ensure_not_paused(&env)?;
+
+        let accrued = Self::get_accrued_fees(env.clone());
+        if accrued <= 0 {
+            return Ok(0);
+        }
+
+        // Only the surplus above escrow liabilities may leave the contract
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let balance = client.balance(&env.current_contract_address());
+        let surplus = balance.saturating_sub(Self::get_total_locked(env.clone()));
+        let amount = accrued.min(surplus);
+        if amount <= 0 {
+            return Err(Error::InsufficientFunds);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AccruedFees, &(accrued - amount));
+        client.transfer(&env.current_contract_address(), &to, &amount);
+
+        events::emit_accrued_fees_withdrawn(
+            &env,
+            events::AccruedFeesWithdrawn {
+                amount,
+                remaining: accrued - amount,
+                to,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(amount)
+    }
+
+    /// Refund everything left in an escrow and reimburse the lock fee (admin only).
+    ///
+    /// Goodwill refund: sends `remaining_amount` back to the depositor
+    /// regardless of the deadline and reimburses the escrow's recorded
+    /// `lock_fee_paid`, so the depositor ends up with their full gross
+    /// deposit. A lock fee that was accrued is paid back out of the accrued
+    /// balance; one that was sent to the fee recipient (or already withdrawn)
+    /// is pulled from the fee recipient, who must authorize it. Any pending
+    /// refund approval or release request is cleared.
+    ///
+    /// Only escrows nothing has been released from qualify, since the lock
+    /// fee covered the whole deposit.
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `NotInitialized` - Contract not initialized
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Not `Locked` or `PartiallyRefunded`
+    /// * `BountyFrozen` - Bounty is frozen
+    /// * `TransferFailed` - The fee recipient couldn't cover the reimbursement
+    pub fn refund_with_fee_reimbursement(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::ensure_not_paused(&env)?;
+
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if Self::is_bounty_frozen_internal(&env, bounty_id) {
+            return Err(Error::BountyFrozen);
+        }
+
+        // Reimburse from wherever the lock fee actually went
+        let from_accrued = escrow.lock_fee_paid > 0
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::LockFeeAccrued(bounty_id))
+            && Self::get_accrued_fees(env.clone()) >= escrow.lock_fee_paid;
+        let fee_recipient = Self::get_fee_config_internal(&env).fee_recipient;
+        if escrow.lock_fee_paid > 0 && !from_accrued && fee_recipient != admin {
+            fee_recipient.require_auth();
+        }
+
+        let refund_amount = escrow.remaining_amount;
+        let depositor = escrow.depositor.clone();
+
+        // Settle escrow state before any external call
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        let refund_record = RefundRecord {
+            amount: refund_amount,
+            recipient: depositor.clone(),
+            mode: RefundMode::Full,
+            timestamp: env.ledger().timestamp(),
+            reason: Some(String::from_str(&env, "lock fee reimbursed")),
+        };
+        Self::record_refund(&env, bounty_id, &mut escrow, refund_record);
+        Self::save_escrow(&env, bounty_id, &mut escrow);
+        Self::adjust_total_locked(&env, -refund_amount);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundApproval(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReleaseRequest(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::LockFeeAccrued(bounty_id));
+        if from_accrued {
+            let accrued = Self::get_accrued_fees(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::AccruedFees, &(accrued - escrow.lock_fee_paid));
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &depositor, &refund_amount);
+
+        if from_accrued {
+            client.transfer(
+                &env.current_contract_address(),
+                &depositor,
+                &escrow.lock_fee_paid,
+            );
+        } else if escrow.lock_fee_paid > 0 {
+            Self::transfer_in(&client, &fee_recipient, &depositor, escrow.lock_fee_paid)?;
+        }
+
+        on_funds_refunded(
+            &env,
+            bounty_id,
+            refund_amount,
+            &depositor,
+            0,
+            RefundMode::Full,
+            &admin,
+        );
+
+        Ok(())
     }
 }
 
@@ -2170,3 +6192,6 @@ mod test;
 
 #[cfg(test)]
 mod pause_tests;
+
+#[cfg(test)]
+mod test_event_ordering;
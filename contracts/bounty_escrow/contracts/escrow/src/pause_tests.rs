@@ -1,7 +1,11 @@
 #[cfg(test)]
 mod pause_tests {
-    use crate::{BountyEscrowContract, BountyEscrowContractClient};
-    use soroban_sdk::{testutils::Address as _, token, Address, Env};
+    use crate::test::init_escrow;
+    use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, RefundMode};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, vec, Address, Env,
+    };
 
     fn create_token<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
         let addr = env.register_stellar_asset_contract(admin.clone());
@@ -17,7 +21,7 @@ mod pause_tests {
         let admin = Address::generate(&env);
         let token = create_token(&env, &admin);
 
-        client.init(&admin, &token.address);
+        init_escrow(&client, &admin, &token.address);
         client.pause();
         assert!(client.is_paused());
     }
@@ -32,7 +36,7 @@ mod pause_tests {
         let admin = Address::generate(&env);
         let token = create_token(&env, &admin);
 
-        client.init(&admin, &token.address);
+        init_escrow(&client, &admin, &token.address);
         client.pause();
         client.lock_funds(&admin, &1, &1000, &9999);
     }
@@ -46,7 +50,7 @@ mod pause_tests {
         let admin = Address::generate(&env);
         let token = create_token(&env, &admin);
 
-        client.init(&admin, &token.address);
+        init_escrow(&client, &admin, &token.address);
         client.pause();
         client.unpause();
         assert!(!client.is_paused());
@@ -62,11 +66,52 @@ mod pause_tests {
         let token = create_token(&env, &admin);
         let recipient = Address::generate(&env);
 
-        client.init(&admin, &token.address);
+        init_escrow(&client, &admin, &token.address);
         client.pause();
         client.emergency_withdraw(&recipient);
     }
 
+    #[test]
+    fn test_emergency_distribute() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = BountyEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let depositor_a = Address::generate(&env);
+        let depositor_b = Address::generate(&env);
+        let token = create_token(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token.address);
+        token_admin.mint(&depositor_a, &600);
+        token_admin.mint(&depositor_b, &400);
+
+        init_escrow(&client, &admin, &token.address);
+        client.lock_funds(&depositor_a, &1, &600, &9_999);
+        env.ledger().set_timestamp(100);
+        client.lock_funds(&depositor_b, &2, &400, &9_999);
+
+        let distributions = vec![&env, (depositor_a.clone(), 600), (depositor_b.clone(), 400)];
+        assert_eq!(
+            client.try_emergency_distribute(&distributions),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        client.pause();
+        assert_eq!(
+            client.try_emergency_distribute(&vec![&env, (depositor_a.clone(), 1_001)]),
+            Err(Ok(Error::InsufficientFunds))
+        );
+        assert_eq!(
+            client.try_emergency_distribute(&vec![&env, (depositor_a.clone(), 0)]),
+            Err(Ok(Error::InvalidAmount))
+        );
+
+        client.emergency_distribute(&distributions);
+        assert_eq!(token.balance(&depositor_a), 600);
+        assert_eq!(token.balance(&depositor_b), 400);
+        assert_eq!(token.balance(&contract_id), 0);
+    }
+
     #[test]
     fn test_pause_state_persists() {
         let env = Env::default();
@@ -76,9 +121,83 @@ mod pause_tests {
         let admin = Address::generate(&env);
         let token = create_token(&env, &admin);
 
-        client.init(&admin, &token.address);
+        init_escrow(&client, &admin, &token.address);
         client.pause();
         assert!(client.is_paused());
         assert!(client.is_paused());
     }
+
+    #[test]
+    fn test_get_paused_since() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = BountyEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let token = create_token(&env, &admin);
+
+        init_escrow(&client, &admin, &token.address);
+        assert_eq!(client.get_paused_since(), None);
+
+        env.ledger().set_timestamp(1_000);
+        client.pause();
+        assert_eq!(client.get_paused_since(), Some(1_000));
+
+        // Pausing again is a no-op and keeps the original timestamp
+        env.ledger().set_timestamp(2_000);
+        client.pause();
+        assert_eq!(client.get_paused_since(), Some(1_000));
+
+        client.unpause();
+        assert_eq!(client.get_paused_since(), None);
+    }
+
+    #[test]
+    fn test_pause_blocks_admin_mutations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = BountyEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let token = create_token(&env, &admin);
+        token::StellarAssetClient::new(&env, &token.address).mint(&depositor, &1_000);
+
+        init_escrow(&client, &admin, &token.address);
+        client.lock_funds(&depositor, &1, &1_000, &9_999);
+        client.pause();
+
+        assert_eq!(
+            client.try_approve_refund(&1, &500, &depositor, &RefundMode::Partial, &None),
+            Err(Ok(Error::ContractPaused))
+        );
+        #[cfg(feature = "fees")]
+        assert_eq!(
+            client.try_update_fee_config(&Some(100), &None, &None, &None, &None),
+            Err(Ok(Error::ContractPaused))
+        );
+        #[cfg(feature = "fees")]
+        assert_eq!(
+            client.try_refund_with_fee_reimbursement(&1),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(
+            client.try_set_whitelist(&depositor, &true),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(client.try_freeze_bounty(&1), Err(Ok(Error::ContractPaused)));
+        assert_eq!(client.try_sunset(), Err(Ok(Error::ContractPaused)));
+
+        // The emergency path and unpause stay available
+        let recipient = Address::generate(&env);
+        client.emergency_withdraw(&recipient);
+        assert_eq!(token.balance(&recipient), 1_000);
+        client.unpause();
+        client.set_whitelist(&depositor, &true);
+        #[cfg(feature = "fees")]
+        {
+            client.update_fee_config(&Some(100), &None, &None, &None, &None);
+            assert_eq!(client.get_fee_config().lock_fee_rate, 100);
+        }
+    }
 }
@@ -25,6 +25,26 @@ fn create_escrow_contract<'a>(e: &Env) -> (BountyEscrowContractClient<'a>, Addre
     (client, contract_id)
 }
 
+/// `init` without a fee config; only builds with the `fees` feature take one
+pub(crate) fn init_escrow(escrow: &BountyEscrowContractClient, admin: &Address, token: &Address) {
+    #[cfg(feature = "fees")]
+    escrow.init(admin, token, &None);
+    #[cfg(not(feature = "fees"))]
+    escrow.init(admin, token);
+}
+
+/// `try_init` counterpart of `init_escrow`
+fn try_init_escrow(
+    escrow: &BountyEscrowContractClient,
+    admin: &Address,
+    token: &Address,
+) -> Result<Result<(), soroban_sdk::ConversionError>, Result<Error, soroban_sdk::InvokeError>> {
+    #[cfg(feature = "fees")]
+    return escrow.try_init(admin, token, &None);
+    #[cfg(not(feature = "fees"))]
+    return escrow.try_init(admin, token);
+}
+
 struct TestSetup<'a> {
     env: Env,
     admin: Address,
@@ -48,7 +68,7 @@ impl<'a> TestSetup<'a> {
         let (token, token_admin) = create_token_contract(&env, &admin);
         let (escrow, escrow_address) = create_escrow_contract(&env);
 
-        escrow.init(&admin, &token.address);
+        init_escrow(&escrow, &admin, &token.address);
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
@@ -220,6 +240,7 @@ fn test_refund_full_after_deadline() {
         &None::<i128>,
         &None::<Address>,
         &RefundMode::Full,
+        &None::<String>,
     );
 
     // Verify state
@@ -261,6 +282,7 @@ fn test_refund_full_before_deadline() {
         &None::<i128>,
         &None::<Address>,
         &RefundMode::Full,
+        &None::<String>,
     );
 }
 
@@ -293,6 +315,7 @@ fn test_refund_partial_after_deadline() {
         &Some(refund_amount),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 
     // Verify state
@@ -339,6 +362,7 @@ fn test_refund_partial_multiple_times() {
         &Some(refund1),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 
     // Second partial refund
@@ -347,6 +371,7 @@ fn test_refund_partial_multiple_times() {
         &Some(refund2),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 
     // Verify state
@@ -384,6 +409,7 @@ fn test_refund_partial_before_deadline() {
         &Some(refund_amount),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 }
 
@@ -415,6 +441,7 @@ fn test_refund_custom_after_deadline() {
         &Some(refund_amount),
         &Some(custom_recipient.clone()),
         &RefundMode::Custom,
+        &None::<String>,
     );
 
     // Verify state
@@ -457,6 +484,7 @@ fn test_refund_custom_before_deadline_without_approval() {
         &Some(refund_amount),
         &Some(custom_recipient),
         &RefundMode::Custom,
+        &None::<String>,
     );
 }
 
@@ -484,6 +512,7 @@ fn test_refund_approval_workflow() {
         &refund_amount,
         &custom_recipient.clone(),
         &RefundMode::Custom,
+        &None::<String>,
     );
 
     // Verify approval exists
@@ -508,6 +537,7 @@ fn test_refund_approval_workflow() {
         &Some(refund_amount),
         &Some(custom_recipient.clone()),
         &RefundMode::Custom,
+        &None::<String>,
     );
 
     // Verify approval was consumed (removed after use)
@@ -548,6 +578,7 @@ fn test_refund_approval_mismatch() {
         &approved_amount,
         &custom_recipient.clone(),
         &RefundMode::Custom,
+        &None::<String>,
     );
 
     // Try to refund with different amount (should fail)
@@ -556,7 +587,58 @@ fn test_refund_approval_mismatch() {
         &Some(requested_amount),
         &Some(custom_recipient),
         &RefundMode::Custom,
+        &None::<String>,
+    );
+}
+
+#[test]
+fn test_refund_approval_replacement_emits_event() {
+    use soroban_sdk::{testutils::Events as _, IntoVal, TryFromVal};
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let custom_recipient = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.approve_refund(
+        &1,
+        &500,
+        &custom_recipient,
+        &RefundMode::Custom,
+        &None::<String>,
+    );
+    let replaced = |env: &Env| {
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| *topics == (symbol_short!("rf_repl"), 1u64).into_val(env))
+            .count()
+    };
+    assert_eq!(replaced(&setup.env), 0);
+
+    // A second approval overwrites the first and reports what it replaced
+    setup.escrow.approve_refund(
+        &1,
+        &300,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None::<String>,
     );
+    assert_eq!(replaced(&setup.env), 1);
+    let (_, _, data) = setup.env.events().all().last().unwrap();
+    let event = events::RefundApprovalReplaced::try_from_val(&setup.env, &data).unwrap();
+    assert_eq!(event.previous.amount, 500);
+    assert_eq!(event.previous.recipient, custom_recipient);
+    assert_eq!(event.replacement.amount, 300);
+    assert_eq!(event.replacement.mode, RefundMode::Partial);
+
+    // Only the latest approval can be used
+    setup
+        .escrow
+        .refund(&1, &Some(300), &None, &RefundMode::Partial, &None::<String>);
+    assert_eq!(setup.token.balance(&setup.depositor), 999_300);
 }
 
 #[test]
@@ -607,6 +689,7 @@ fn test_refund_history_tracking() {
         &Some(refund1),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 
     // Second refund (Partial)
@@ -615,6 +698,7 @@ fn test_refund_history_tracking() {
         &Some(refund2),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 
     // Third refund (Full remaining - should complete the refund)
@@ -624,6 +708,7 @@ fn test_refund_history_tracking() {
         &Some(remaining),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 
     // Verify refund history
@@ -677,6 +762,7 @@ fn test_refund_history_with_custom_recipients() {
         &Some(refund1),
         &Some(recipient1.clone()),
         &RefundMode::Custom,
+        &None::<String>,
     );
 
     // Second custom refund
@@ -685,6 +771,7 @@ fn test_refund_history_with_custom_recipients() {
         &Some(refund2),
         &Some(recipient2.clone()),
         &RefundMode::Custom,
+        &None::<String>,
     );
 
     // Verify refund history
@@ -713,9 +800,13 @@ fn test_refund_invalid_amount_zero() {
     setup.env.ledger().set_timestamp(deadline + 1);
 
     // Try to refund zero amount
-    setup
-        .escrow
-        .refund(&bounty_id, &Some(0), &None::<Address>, &RefundMode::Partial);
+    setup.escrow.refund(
+        &bounty_id,
+        &Some(0),
+        &None::<Address>,
+        &RefundMode::Partial,
+        &None::<String>,
+    );
 }
 
 #[test]
@@ -739,6 +830,7 @@ fn test_refund_invalid_amount_exceeds_remaining() {
         &Some(refund_amount),
         &None::<Address>,
         &RefundMode::Partial,
+        &None::<String>,
     );
 }
 
@@ -763,6 +855,7 @@ fn test_refund_custom_missing_amount() {
         &None::<i128>,
         &Some(custom_recipient),
         &RefundMode::Custom,
+        &None::<String>,
     );
 }
 
@@ -787,6 +880,7 @@ fn test_refund_custom_missing_recipient() {
         &Some(refund_amount),
         &None::<Address>,
         &RefundMode::Custom,
+        &None::<String>,
     );
 }
 
@@ -822,9 +916,13 @@ fn test_get_refund_eligibility() {
     // With approval before deadline
     setup.env.ledger().set_timestamp(deadline - 100);
     let custom_recipient = Address::generate(&setup.env);
-    setup
-        .escrow
-        .approve_refund(&bounty_id, &500, &custom_recipient, &RefundMode::Custom);
+    setup.escrow.approve_refund(
+        &bounty_id,
+        &500,
+        &custom_recipient,
+        &RefundMode::Custom,
+        &None::<String>,
+    );
 
     let (can_refund, deadline_passed, remaining, approval) =
         setup.escrow.get_refund_eligibility(&bounty_id);
@@ -852,6 +950,33 @@ fn test_get_balance() {
     assert_eq!(setup.escrow.get_balance(), amount);
 }
 
+#[test]
+fn test_get_limits() {
+    let setup = TestSetup::new();
+    let limits = setup.escrow.get_limits();
+    assert_eq!(limits.max_batch_size, MAX_BATCH_SIZE);
+    assert_eq!(limits.min_lock_amount, 1);
+    #[cfg(feature = "fees")]
+    assert_eq!(limits.max_fee_rate, 1_000);
+    #[cfg(not(feature = "fees"))]
+    assert_eq!(limits.max_fee_rate, 0);
+    assert_eq!(limits.max_deadline_horizon, u64::MAX);
+}
+
+#[test]
+fn test_get_token() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_token(), setup.token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")] // NotInitialized
+fn test_get_token_not_initialized() {
+    let env = Env::default();
+    let (escrow, _) = create_escrow_contract(&env);
+    escrow.get_token();
+}
+
 // ============================================================================
 // BATCH OPERATIONS TESTS
 // ============================================================================
@@ -901,6 +1026,65 @@ fn test_batch_lock_funds_success() {
     assert_eq!(setup.escrow.get_balance(), 6000);
 }
 
+#[test]
+fn test_batch_lock_funds_detailed() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 7,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 2000,
+            deadline,
+        },
+    ];
+
+    let ids = setup.escrow.batch_lock_funds_detailed(&items);
+    assert_eq!(ids, vec![&setup.env, 7u64, 3u64]);
+    assert_eq!(setup.escrow.get_balance(), 3000);
+
+    // Failure returns the error, not a partial list
+    let res = setup.escrow.try_batch_lock_funds_detailed(&items);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+}
+
+#[test]
+fn test_batch_lock_funds_past_deadline() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(5000);
+    let now = setup.env.ledger().timestamp();
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline: now + 1000,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline: now,
+        },
+    ];
+
+    let res = setup.escrow.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Ok(Error::InvalidDeadline)));
+
+    // Nothing was locked
+    assert_eq!(setup.escrow.get_balance(), 0);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #10)")] // InvalidBatchSize
 fn test_batch_lock_funds_empty() {
@@ -1182,3 +1366,3224 @@ fn test_batch_operations_large_batch() {
     let release_count = setup.escrow.batch_release_funds(&release_items);
     assert_eq!(release_count, 10);
 }
+
+// ============================================================================
+// BATCH INCREASE AMOUNT TESTS
+// ============================================================================
+
+#[test]
+fn test_batch_increase_amount_success() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+
+    let items = vec![&setup.env, (1u64, 500i128), (2u64, 250i128)];
+    let count = setup.escrow.batch_increase_amount(&setup.depositor, &items);
+    assert_eq!(count, 2);
+
+    let escrow1 = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow1.amount, 1500);
+    assert_eq!(escrow1.remaining_amount, 1500);
+    assert_eq!(escrow1.status, EscrowStatus::Locked);
+
+    let escrow2 = setup.escrow.get_escrow_info(&2);
+    assert_eq!(escrow2.amount, 2250);
+    assert_eq!(escrow2.remaining_amount, 2250);
+
+    assert_eq!(setup.token.balance(&setup.escrow_address), 3750);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")] // Unauthorized
+fn test_batch_increase_amount_not_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &1000);
+
+    let items = vec![&setup.env, (1u64, 500i128)];
+    setup.escrow.batch_increase_amount(&other, &items);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")] // FundsNotLocked
+fn test_batch_increase_amount_released_bounty() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    setup.escrow.release_funds(&2, &setup.contributor);
+
+    // Entire batch must revert because bounty 2 is no longer locked
+    let items = vec![&setup.env, (1u64, 500i128), (2u64, 500i128)];
+    setup.escrow.batch_increase_amount(&setup.depositor, &items);
+}
+
+#[test]
+fn test_batch_increase_amount_overflow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    // No real token supports balances this large, so plant the escrow directly
+    setup.env.as_contract(&setup.escrow_address, || {
+        let key = DataKey::Escrow(1);
+        let mut escrow: Escrow = setup.env.storage().persistent().get(&key).unwrap();
+        escrow.amount = i128::MAX - 10;
+        escrow.remaining_amount = i128::MAX - 10;
+        escrow.gross_amount = i128::MAX - 10;
+        setup.env.storage().persistent().set(&key, &escrow);
+    });
+
+    let items = vec![&setup.env, (1u64, 100i128)];
+    assert_eq!(
+        setup
+            .escrow
+            .try_batch_increase_amount(&setup.depositor, &items),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).amount, i128::MAX - 10);
+    assert_eq!(setup.token.balance(&setup.depositor), 999_000);
+
+    // Topping up to exactly i128::MAX is still fine
+    let items = vec![&setup.env, (1u64, 10i128)];
+    setup.escrow.batch_increase_amount(&setup.depositor, &items);
+    assert_eq!(setup.escrow.get_escrow_info(&1).amount, i128::MAX);
+}
+
+// ============================================================================
+// BATCH PARTIAL RELEASE TESTS
+// ============================================================================
+
+#[test]
+fn test_batch_release_partial_until_drained() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+
+    let contributor2 = Address::generate(&setup.env);
+
+    // Release 30% of each bounty
+    let items = vec![
+        &setup.env,
+        (1u64, setup.contributor.clone(), 300i128),
+        (2u64, contributor2.clone(), 600i128),
+    ];
+    assert_eq!(setup.escrow.batch_release_partial(&items), 2);
+
+    let escrow1 = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow1.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow1.remaining_amount, 700);
+    let escrow2 = setup.escrow.get_escrow_info(&2);
+    assert_eq!(escrow2.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow2.remaining_amount, 1400);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 300);
+    assert_eq!(setup.token.balance(&contributor2), 600);
+
+    // Drain bounty 1, release a bit more from bounty 2
+    let items = vec![
+        &setup.env,
+        (1u64, setup.contributor.clone(), 700i128),
+        (2u64, contributor2.clone(), 400i128),
+    ];
+    setup.escrow.batch_release_partial(&items);
+
+    let escrow1 = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow1.status, EscrowStatus::Released);
+    assert_eq!(escrow1.remaining_amount, 0);
+    let escrow2 = setup.escrow.get_escrow_info(&2);
+    assert_eq!(escrow2.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow2.remaining_amount, 1000);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(setup.token.balance(&contributor2), 1000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")] // InvalidAmount
+fn test_batch_release_partial_exceeds_remaining() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    let items = vec![&setup.env, (1u64, setup.contributor.clone(), 1001i128)];
+    setup.escrow.batch_release_partial(&items);
+}
+
+#[test]
+fn test_refund_after_partial_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    let items = vec![&setup.env, (1u64, setup.contributor.clone(), 400i128)];
+    setup.escrow.batch_release_partial(&items);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(
+        &1,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 400);
+}
+
+// ============================================================================
+// RATE LIMIT MODE TESTS
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_rate_limit_enforce_mode_blocks() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.update_rate_limit_config(&3600, &1, &0);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_lock_funds_checks_auth_before_rate_limit() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.update_rate_limit_config(&3600, &1, &0);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+
+    // An unauthorized call is rejected before it reaches the rate limiter
+    setup.env.set_auths(&[]);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+}
+
+#[test]
+fn test_rate_limit_monitor_mode_does_not_block() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.update_rate_limit_config(&3600, &1, &60);
+    setup
+        .escrow
+        .set_rate_limit_mode(&anti_abuse::RateLimitMode::Monitor);
+
+    // Exceeds both the cooldown and the window limit, but is only recorded
+    for bounty_id in 1..=3u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &100, &deadline);
+    }
+    assert_eq!(setup.token.balance(&setup.escrow_address), 300);
+
+    // Updating limits keeps the monitor mode
+    setup.escrow.update_rate_limit_config(&3600, &1, &0);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &4, &100, &deadline);
+}
+
+#[test]
+fn test_admin_exempt_from_rate_limit() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    let mut items = Vec::new(&setup.env);
+    for bounty_id in 1..=5u64 {
+        items.push_back(LockFundsItem {
+            bounty_id,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+        });
+    }
+    setup.escrow.batch_lock_funds(&items);
+
+    // The admin is not whitelisted but may exceed max_operations
+    setup.escrow.update_rate_limit_config(&3600, &2, &60);
+    for bounty_id in 1..=5u64 {
+        setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    }
+    assert_eq!(setup.token.balance(&setup.contributor), 500);
+    assert!(!setup.escrow.get_rate_limit_state(&setup.admin).2);
+}
+
+#[test]
+fn test_get_rate_limit_config() {
+    let setup = TestSetup::new();
+
+    // Defaults until configured
+    let config = setup.escrow.get_rate_limit_config();
+    assert_eq!(config.window_size, 3600);
+    assert_eq!(config.max_operations, 10);
+    assert_eq!(config.cooldown_period, 60);
+    assert_eq!(config.mode, anti_abuse::RateLimitMode::Enforce);
+
+    setup.escrow.update_rate_limit_config(&7200, &5, &120);
+    setup
+        .escrow
+        .set_rate_limit_mode(&anti_abuse::RateLimitMode::Monitor);
+    assert_eq!(
+        setup.escrow.get_rate_limit_config(),
+        anti_abuse::AntiAbuseConfig {
+            window_size: 7200,
+            max_operations: 5,
+            cooldown_period: 120,
+            mode: anti_abuse::RateLimitMode::Monitor,
+        }
+    );
+}
+
+#[test]
+fn test_get_rate_limit_state() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup.escrow.update_rate_limit_config(&3600, &2, &60);
+
+    // Fresh address: nothing recorded, not blocked
+    assert_eq!(
+        setup.escrow.get_rate_limit_state(&setup.depositor),
+        (0, 0, false)
+    );
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+
+    // In cooldown right after an operation
+    setup.env.ledger().set_timestamp(1010);
+    assert_eq!(
+        setup.escrow.get_rate_limit_state(&setup.depositor),
+        (1, 50, true)
+    );
+
+    // Cooldown over, window still has room
+    setup.env.ledger().set_timestamp(1060);
+    assert_eq!(
+        setup.escrow.get_rate_limit_state(&setup.depositor),
+        (1, 0, false)
+    );
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+
+    // Window limit reached
+    setup.env.ledger().set_timestamp(1200);
+    assert_eq!(
+        setup.escrow.get_rate_limit_state(&setup.depositor),
+        (2, 0, true)
+    );
+
+    // New window resets the count
+    setup.env.ledger().set_timestamp(1000 + 3600);
+    assert_eq!(
+        setup.escrow.get_rate_limit_state(&setup.depositor),
+        (0, 0, false)
+    );
+}
+
+#[test]
+fn test_failed_reinit_leaves_rate_limit_state_untouched() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let other = Address::generate(&setup.env);
+
+    for _ in 0..3 {
+        assert_eq!(
+            try_init_escrow(&setup.escrow, &other, &setup.token.address),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+    }
+    assert_eq!(setup.escrow.get_rate_limit_state(&other), (0, 0, false));
+}
+
+// ============================================================================
+// BOUNTY FREEZE TESTS
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")] // BountyFrozen
+fn test_freeze_bounty_blocks_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.freeze_bounty(&1);
+    assert!(setup.escrow.is_bounty_frozen(&1));
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")] // BountyFrozen
+fn test_freeze_bounty_blocks_refund() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.freeze_bounty(&1);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(
+        &1,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+}
+
+#[test]
+fn test_unfreeze_bounty_allows_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    setup.escrow.freeze_bounty(&1);
+
+    // Other bounties are unaffected
+    assert!(!setup.escrow.is_bounty_frozen(&2));
+    setup.escrow.release_funds(&2, &setup.contributor);
+
+    setup.escrow.unfreeze_bounty(&1);
+    assert!(!setup.escrow.is_bounty_frozen(&1));
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 2000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")] // BountyNotFound
+fn test_freeze_bounty_not_found() {
+    let setup = TestSetup::new();
+    setup.escrow.freeze_bounty(&99);
+}
+
+#[test]
+fn test_freeze_reflected_in_index() {
+    use crate::indexed::indexed_storage::BountyStatus;
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let index_status = |bounty_id: u64| {
+        setup.env.as_contract(&setup.escrow_address, || {
+            indexed::indexed_storage::get_bounty(&setup.env, bounty_id)
+                .unwrap()
+                .status
+        })
+    };
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .batch_release_partial(&vec![&setup.env, (1u64, setup.contributor.clone(), 400)]);
+
+    setup.escrow.freeze_bounty(&1);
+    assert_eq!(index_status(1), BountyStatus::Frozen);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Frozen), 1);
+    assert_eq!(
+        setup
+            .escrow
+            .count_by_status(&BountyStatus::PartiallyReleased),
+        0
+    );
+
+    // Rebuilding the index keeps the freeze
+    setup.escrow.reindex(&vec![&setup.env, 1u64]);
+    assert_eq!(index_status(1), BountyStatus::Frozen);
+
+    // Unfreezing restores the escrow's own status
+    setup.escrow.unfreeze_bounty(&1);
+    assert_eq!(index_status(1), BountyStatus::PartiallyReleased);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Frozen), 0);
+}
+
+// ============================================================================
+// REFUND GRACE PERIOD TESTS
+// ============================================================================
+
+#[test]
+fn test_refund_grace_period_boundary() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    assert_eq!(setup.escrow.get_refund_grace_period(), 0);
+    setup.escrow.set_refund_grace_period(&500);
+    assert_eq!(setup.escrow.get_refund_grace_period(), 500);
+
+    // Deadline reached but still within the grace period
+    setup.env.ledger().set_timestamp(deadline + 499);
+    let (can_refund, deadline_passed, _, _) = setup.escrow.get_refund_eligibility(&1);
+    assert!(!can_refund);
+    assert!(!deadline_passed);
+    let res = setup.escrow.try_refund(
+        &1,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+    assert_eq!(res, Err(Ok(Error::DeadlineNotPassed)));
+
+    // Exactly at deadline + grace refunds open
+    setup.env.ledger().set_timestamp(deadline + 500);
+    let (can_refund, deadline_passed, _, _) = setup.escrow.get_refund_eligibility(&1);
+    assert!(can_refund);
+    assert!(deadline_passed);
+    setup.escrow.refund(
+        &1,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_grace_period_default_zero() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline);
+    let (can_refund, deadline_passed, _, _) = setup.escrow.get_refund_eligibility(&1);
+    assert!(can_refund);
+    assert!(deadline_passed);
+}
+
+// ============================================================================
+// CONTRIBUTOR HISTORY TESTS
+// ============================================================================
+
+#[test]
+fn test_contributor_history_tracks_releases() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+
+    for bounty_id in 1..=3u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+    }
+
+    assert_eq!(
+        setup.escrow.get_bounties_by_contributor(&setup.contributor),
+        vec![&setup.env]
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    // Two partial payouts on the same bounty count as one bounty
+    let items = vec![
+        &setup.env,
+        (2u64, setup.contributor.clone(), 300i128),
+        (3u64, other.clone(), 1000i128),
+    ];
+    setup.escrow.batch_release_partial(&items);
+    let items = vec![&setup.env, (2u64, setup.contributor.clone(), 700i128)];
+    setup.escrow.batch_release_partial(&items);
+
+    assert_eq!(
+        setup.escrow.get_bounties_by_contributor(&setup.contributor),
+        vec![&setup.env, 1u64, 2u64]
+    );
+    assert_eq!(
+        setup.escrow.get_bounties_by_contributor(&other),
+        vec![&setup.env, 3u64]
+    );
+
+    let stats = setup.escrow.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.total_bounties, 2);
+    assert_eq!(stats.total_payouts, 3);
+    assert_eq!(stats.total_received_value, 2000);
+}
+
+// ============================================================================
+// REFUND REASON TESTS
+// ============================================================================
+
+#[test]
+fn test_refund_records_reason() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let custom_recipient = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    // Reason given at approval is used when the refund itself omits one
+    let dispute = String::from_str(&setup.env, "dispute resolved");
+    setup.escrow.approve_refund(
+        &1,
+        &300,
+        &custom_recipient,
+        &RefundMode::Custom,
+        &Some(dispute.clone()),
+    );
+    setup.escrow.refund(
+        &1,
+        &Some(300),
+        &Some(custom_recipient.clone()),
+        &RefundMode::Custom,
+        &None::<String>,
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let correction = String::from_str(&setup.env, "correction");
+    setup.escrow.refund(
+        &1,
+        &Some(200),
+        &None::<Address>,
+        &RefundMode::Partial,
+        &Some(correction.clone()),
+    );
+    setup.escrow.refund(
+        &1,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+
+    let history = setup.escrow.get_refund_history(&1);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().reason, Some(dispute));
+    assert_eq!(history.get(1).unwrap().reason, Some(correction));
+    assert_eq!(history.get(2).unwrap().reason, None);
+}
+
+// ============================================================================
+// RELEASE CONSERVATION TESTS
+// ============================================================================
+
+#[test]
+fn test_release_pays_remaining_amount() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+
+    // Simulate escrows that have already paid part of their balance out
+    setup.env.as_contract(&setup.escrow_address, || {
+        for bounty_id in 1..=2u64 {
+            let mut escrow: Escrow = setup
+                .env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap();
+            escrow.remaining_amount = 600;
+            setup
+                .env
+                .storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+        }
+    });
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 600);
+
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    setup.escrow.batch_release_funds(&items);
+    assert_eq!(setup.token.balance(&setup.contributor), 1200);
+
+    for bounty_id in 1..=2u64 {
+        let escrow = setup.escrow.get_escrow_info(&bounty_id);
+        assert_eq!(escrow.status, EscrowStatus::Released);
+        assert_eq!(escrow.remaining_amount, 0);
+    }
+}
+
+// ============================================================================
+// TTL MANAGEMENT TESTS
+// ============================================================================
+
+#[test]
+fn test_bump_escrows_ttl() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+
+    // Missing ids are skipped
+    let ids = vec![&setup.env, 1u64, 2u64, 99u64];
+    assert_eq!(setup.escrow.bump_escrows_ttl(&ids), 2);
+
+    setup.env.as_contract(&setup.escrow_address, || {
+        for bounty_id in 1..=2u64 {
+            let ttl = setup
+                .env
+                .storage()
+                .persistent()
+                .get_ttl(&DataKey::Escrow(bounty_id));
+            assert_eq!(ttl, ESCROW_TTL_EXTEND_TO);
+        }
+    });
+
+    assert_eq!(setup.escrow.bump_escrows_ttl(&vec![&setup.env]), 0);
+}
+
+#[test]
+fn test_bump_config_ttl() {
+    use soroban_sdk::testutils::storage::Instance as _;
+
+    let setup = TestSetup::new();
+    let instance_ttl = || {
+        setup.env.as_contract(&setup.escrow_address, || {
+            setup.env.storage().instance().get_ttl()
+        })
+    };
+    assert_eq!(instance_ttl(), INSTANCE_TTL_EXTEND_TO);
+
+    // Let the TTL run down past the threshold, then bump it back up
+    let sequence = setup.env.ledger().sequence();
+    setup
+        .env
+        .ledger()
+        .set_sequence_number(sequence + INSTANCE_TTL_EXTEND_TO - 100);
+    assert_eq!(instance_ttl(), 100);
+    setup.escrow.bump_config_ttl();
+    assert_eq!(instance_ttl(), INSTANCE_TTL_EXTEND_TO);
+
+    let (uninitialized, _) = create_escrow_contract(&setup.env);
+    assert_eq!(
+        uninitialized.try_bump_config_ttl(),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_bump_escrow_ttl_not_found() {
+    let setup = TestSetup::new();
+    let res = setup.escrow.try_bump_escrow_ttl(&1);
+    assert_eq!(res, Err(Ok(Error::BountyNotFound)));
+}
+
+// ============================================================================
+// CLIENT REFERENCE TESTS
+// ============================================================================
+
+#[test]
+fn test_get_escrow_by_ref() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let client_ref = BytesN::from_array(&setup.env, &[7u8; 32]);
+
+    setup
+        .escrow
+        .lock_funds_with_ref(&setup.depositor, &42, &1000, &deadline, &client_ref);
+
+    // Retrying after a lost response doesn't lock twice
+    setup
+        .escrow
+        .lock_funds_with_ref(&setup.depositor, &42, &1000, &deadline, &client_ref);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+
+    let (bounty_id, escrow) = setup
+        .escrow
+        .get_escrow_by_ref(&setup.depositor, &client_ref);
+    assert_eq!(bounty_id, 42);
+    assert_eq!(escrow.amount, 1000);
+
+    // Reusing the reference for another bounty is rejected
+    let res =
+        setup
+            .escrow
+            .try_lock_funds_with_ref(&setup.depositor, &43, &1000, &deadline, &client_ref);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+
+    // References are scoped per depositor
+    let other = Address::generate(&setup.env);
+    let res = setup.escrow.try_get_escrow_by_ref(&other, &client_ref);
+    assert_eq!(res, Err(Ok(Error::BountyNotFound)));
+
+    // Reassigning the bounty re-points its reference
+    setup.escrow.reassign_bounty(&42, &50);
+    let (bounty_id, escrow) = setup
+        .escrow
+        .get_escrow_by_ref(&setup.depositor, &client_ref);
+    assert_eq!(bounty_id, 50);
+    assert_eq!(escrow.amount, 1000);
+}
+
+// ============================================================================
+// LARGE LOCK CO-SIGNATURE TESTS
+// ============================================================================
+
+#[test]
+fn test_large_lock_requires_admin_cosign() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert_eq!(setup.escrow.get_large_lock_threshold(), None);
+    setup.escrow.set_large_lock_threshold(&Some(5000));
+    assert_eq!(setup.escrow.get_large_lock_threshold(), Some(5000));
+
+    // Below threshold: only the depositor signs
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &4999, &deadline);
+    let signers = setup.env.auths();
+    assert!(signers.iter().all(|(addr, _)| *addr != setup.admin));
+
+    // At threshold: admin co-signs too
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &5000, &deadline);
+    let signers = setup.env.auths();
+    assert!(signers.iter().any(|(addr, _)| *addr == setup.admin));
+    assert!(signers.iter().any(|(addr, _)| *addr == setup.depositor));
+
+    setup.escrow.set_large_lock_threshold(&None);
+    assert_eq!(setup.escrow.get_large_lock_threshold(), None);
+}
+
+#[test]
+fn test_large_lock_threshold_rejects_non_positive() {
+    let setup = TestSetup::new();
+    let res = setup.escrow.try_set_large_lock_threshold(&Some(0));
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+}
+
+// ============================================================================
+// MONITORING SNAPSHOT TESTS
+// ============================================================================
+
+#[test]
+fn test_get_monitoring_snapshot() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = 5000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+
+    setup.env.ledger().set_timestamp(1200);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+
+    let snapshot = setup.escrow.get_monitoring_snapshot();
+    // init + two locks
+    assert_eq!(snapshot.total_operations, 3);
+    assert_eq!(snapshot.error_count, 0);
+    assert_eq!(snapshot.error_rate, 0);
+    assert!(snapshot.is_healthy);
+    assert_eq!(snapshot.last_operation, 1200);
+    assert_eq!(snapshot.timestamp, 1200);
+}
+
+#[test]
+fn test_get_operation_metrics() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    // A failure recorded by a call that still commits
+    setup.env.as_contract(&setup.escrow_address, || {
+        monitoring::track_operation(
+            &setup.env,
+            symbol_short!("release"),
+            setup.admin.clone(),
+            false,
+        );
+    });
+
+    assert_eq!(
+        setup.escrow.get_operation_metrics(&symbol_short!("lock")),
+        (1, 0)
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_operation_metrics(&symbol_short!("release")),
+        (2, 1)
+    );
+    assert_eq!(
+        setup.escrow.get_operation_metrics(&symbol_short!("refund")),
+        (0, 0)
+    );
+}
+
+#[test]
+fn test_reset_monitoring_period() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = 5000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+
+    setup.env.ledger().set_timestamp(2000);
+    let snapshot = setup.escrow.reset_monitoring_period();
+    // init + lock
+    assert_eq!(snapshot.total_operations, 2);
+    assert_eq!(snapshot.total_errors, 0);
+    assert_eq!(snapshot.timestamp, 2000);
+
+    // Live counters start over, history is kept
+    assert_eq!(setup.escrow.get_monitoring_snapshot().total_operations, 0);
+    let stored = setup.escrow.get_monitoring_period(&2000).unwrap();
+    assert_eq!(stored.total_operations, 2);
+    assert!(setup.escrow.get_monitoring_period(&1000).is_none());
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+    assert_eq!(setup.escrow.get_monitoring_snapshot().total_operations, 1);
+}
+
+#[test]
+fn test_batch_release_best_effort_skips_invalid_items() {
+    use crate::indexed::{ErrorOccurred, ErrorType};
+    use soroban_sdk::{testutils::Events as _, TryFromVal};
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &200, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &300, &deadline);
+    setup.escrow.freeze_bounty(&3);
+
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 99,
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: other.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 3,
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: other.clone(),
+        },
+    ];
+
+    let result = setup.escrow.batch_release_best_effort(&items);
+    assert_eq!(result.successful, vec![&setup.env, 1u64, 2u64]);
+    assert_eq!(result.total_amount, 300);
+    assert_eq!(
+        result.failed,
+        vec![
+            &setup.env,
+            BatchItemError {
+                index: 1,
+                bounty_id: 99,
+                error_code: Error::BountyNotFound as u32,
+            },
+            BatchItemError {
+                index: 3,
+                bounty_id: 3,
+                error_code: Error::BountyFrozen as u32,
+            },
+            BatchItemError {
+                index: 4,
+                bounty_id: 1,
+                error_code: Error::FundsNotLocked as u32,
+            },
+        ]
+    );
+
+    assert_eq!(setup.token.balance(&setup.contributor), 100);
+    assert_eq!(setup.token.balance(&other), 200);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&3).status,
+        EscrowStatus::Locked
+    );
+
+    // The batch doesn't revert, so each skipped item's error event sticks
+    let errors: Vec<(ErrorType, Option<u64>)> = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter_map(|(_, topics, data)| {
+            let topic = Symbol::try_from_val(&setup.env, &topics.get(0)?).ok()?;
+            (topic == symbol_short!("error")).then(|| {
+                let event = ErrorOccurred::try_from_val(&setup.env, &data).unwrap();
+                (event.error_type, event.bounty_id)
+            })
+        })
+        .fold(Vec::new(&setup.env), |mut acc, error| {
+            acc.push_back(error);
+            acc
+        });
+    assert_eq!(
+        errors,
+        vec![
+            &setup.env,
+            (ErrorType::BountyNotFound, Some(99)),
+            (ErrorType::InvalidStatus, Some(3)),
+            (ErrorType::InvalidStatus, Some(1)),
+        ]
+    );
+}
+
+#[test]
+fn test_refund_full_and_partial_with_approval_before_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    let initial_balance = setup.token.balance(&setup.depositor);
+
+    // Without approval the deadline still applies
+    let res = setup
+        .escrow
+        .try_refund(&1, &Some(300), &None, &RefundMode::Partial, &None::<String>);
+    assert_eq!(res, Err(Ok(Error::DeadlineNotPassed)));
+
+    // Approval must match the requested amount
+    setup.escrow.approve_refund(
+        &1,
+        &300,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None::<String>,
+    );
+    let res = setup
+        .escrow
+        .try_refund(&1, &Some(200), &None, &RefundMode::Partial, &None::<String>);
+    assert_eq!(res, Err(Ok(Error::RefundNotApproved)));
+
+    setup
+        .escrow
+        .refund(&1, &Some(300), &None, &RefundMode::Partial, &None::<String>);
+    assert_eq!(setup.token.balance(&setup.depositor), initial_balance + 300);
+
+    // Approval was consumed; a full refund needs its own
+    let res = setup
+        .escrow
+        .try_refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+    assert_eq!(res, Err(Ok(Error::DeadlineNotPassed)));
+
+    setup.escrow.approve_refund(
+        &1,
+        &700,
+        &setup.depositor,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        initial_balance + 1000
+    );
+}
+
+#[test]
+fn test_refund_rejects_recipient_for_depositor_modes() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.env.ledger().set_timestamp(deadline + 1);
+
+    for mode in [RefundMode::Full, RefundMode::Partial] {
+        let res = setup
+            .escrow
+            .try_refund(&1, &None, &Some(other.clone()), &mode, &None::<String>);
+        assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+    }
+    assert_eq!(setup.token.balance(&other), 0);
+
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+// ============================================================================
+// REENTRANCY TESTS - Contract contributor
+// ============================================================================
+
+/// Token whose `transfer` notifies a receiver contract, like a token with
+/// receive hooks would.
+#[contract]
+struct HookToken;
+
+#[contracttype]
+enum HookTokenKey {
+    Receiver,
+}
+
+#[contractimpl]
+impl HookToken {
+    pub fn set_receiver(env: Env, receiver: Address) {
+        env.storage()
+            .instance()
+            .set(&HookTokenKey::Receiver, &receiver);
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        1_000_000
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, _amount: i128) {
+        let receiver: Option<Address> = env.storage().instance().get(&HookTokenKey::Receiver);
+        if receiver == Some(to.clone()) {
+            ReentrantReceiverClient::new(&env, &to).on_receive(&from);
+        }
+    }
+}
+
+/// Contributor contract that tries to release the same bounty again from its
+/// receive hook.
+#[contract]
+struct ReentrantReceiver;
+
+#[contractimpl]
+impl ReentrantReceiver {
+    pub fn on_receive(env: Env, escrow: Address) {
+        BountyEscrowContractClient::new(&env, &escrow)
+            .release_funds(&1, &env.current_contract_address());
+    }
+}
+
+#[test]
+fn test_release_to_reentrant_contract_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let token_id = env.register_contract(None, HookToken);
+    let receiver = env.register_contract(None, ReentrantReceiver);
+    let (escrow, _escrow_address) = create_escrow_contract(&env);
+
+    init_escrow(&escrow, &admin, &token_id);
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &1000, &deadline);
+    HookTokenClient::new(&env, &token_id).set_receiver(&receiver);
+
+    // The nested release_funds from the receive hook is rejected as contract
+    // re-entry (the ReentrancyGuard backs this up), aborting the whole call
+    let res = escrow.try_release_funds(&1, &receiver);
+    assert_eq!(res, Err(Err(soroban_sdk::InvokeError::Abort)));
+
+    // Nothing was committed: the bounty is still locked and can't be stuck
+    let info = escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert_eq!(info.remaining_amount, 1000);
+    assert!(!escrow.is_reentrancy_locked());
+}
+
+/// Observer that records every transition it is told about.
+#[contract]
+struct TransitionRecorder;
+
+#[contractimpl]
+impl TransitionRecorder {
+    pub fn notify_transition(
+        env: Env,
+        bounty_id: u64,
+        old_status: Option<EscrowStatus>,
+        new_status: EscrowStatus,
+    ) {
+        let mut log: Vec<(u64, Option<EscrowStatus>, EscrowStatus)> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("log"))
+            .unwrap_or(Vec::new(&env));
+        log.push_back((bounty_id, old_status, new_status));
+        env.storage().instance().set(&symbol_short!("log"), &log);
+    }
+
+    pub fn log(env: Env) -> Vec<(u64, Option<EscrowStatus>, EscrowStatus)> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("log"))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+/// Observer that always fails. Kept in its own module so its contract spec
+/// doesn't clash with `TransitionRecorder::notify_transition`.
+mod failing_observer {
+    use crate::EscrowStatus;
+    use soroban_sdk::{contract, contractimpl, Env};
+
+    #[contract]
+    pub struct FailingObserver;
+
+    #[contractimpl]
+    impl FailingObserver {
+        pub fn notify_transition(
+            _env: Env,
+            _bounty_id: u64,
+            _old_status: Option<EscrowStatus>,
+            _new_status: EscrowStatus,
+        ) {
+            panic!("observer unavailable");
+        }
+    }
+}
+
+#[test]
+fn test_state_observer_notified_of_transitions() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let observer = setup.env.register_contract(None, TransitionRecorder);
+    setup.escrow.set_state_observer(&Some(observer.clone()));
+    assert_eq!(setup.escrow.get_state_observer(), Some(observer.clone()));
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .batch_release_partial(&vec![&setup.env, (1u64, setup.contributor.clone(), 400)]);
+    setup
+        .escrow
+        .batch_release_partial(&vec![&setup.env, (1u64, setup.contributor.clone(), 600)]);
+
+    assert_eq!(
+        TransitionRecorderClient::new(&setup.env, &observer).log(),
+        vec![
+            &setup.env,
+            (1u64, None, EscrowStatus::Locked),
+            (
+                1u64,
+                Some(EscrowStatus::Locked),
+                EscrowStatus::PartiallyReleased
+            ),
+            (
+                1u64,
+                Some(EscrowStatus::PartiallyReleased),
+                EscrowStatus::Released
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_failing_state_observer_does_not_revert() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let observer = setup
+        .env
+        .register_contract(None, failing_observer::FailingObserver);
+    setup.escrow.set_state_observer(&Some(observer));
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+
+    setup.escrow.set_state_observer(&None);
+    assert_eq!(setup.escrow.get_state_observer(), None);
+}
+
+#[test]
+fn test_refund_with_nothing_remaining() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    // Simulate an escrow left partially refunded with nothing remaining
+    setup.env.as_contract(&setup.escrow_address, || {
+        let key = DataKey::Escrow(1);
+        let mut escrow: Escrow = setup.env.storage().persistent().get(&key).unwrap();
+        escrow.status = EscrowStatus::PartiallyRefunded;
+        escrow.remaining_amount = 0;
+        setup.env.storage().persistent().set(&key, &escrow);
+    });
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let res = setup
+        .escrow
+        .try_refund(&1, &None, &None, &RefundMode::Full, &None);
+    assert_eq!(res, Err(Ok(Error::NothingToRefund)));
+}
+
+#[test]
+fn test_partial_refunds_to_zero_mark_refunded() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &Some(400), &None, &RefundMode::Partial, &None);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::PartiallyRefunded
+    );
+    setup
+        .escrow
+        .refund(&1, &Some(600), &None, &RefundMode::Partial, &None);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_get_escrow_summary() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &Some(300), &None, &RefundMode::Partial, &None);
+
+    assert_eq!(
+        setup.escrow.get_escrow_summary(&1),
+        EscrowSummary {
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            remaining_amount: 700,
+            status: EscrowStatus::PartiallyRefunded,
+            deadline,
+        }
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_summary(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_deadline_policy() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let now = setup.env.ledger().timestamp();
+    let day = 24 * 60 * 60;
+
+    assert_eq!(
+        setup.escrow.try_set_deadline_policy(&(7 * day), &day),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    setup.escrow.set_deadline_policy(&(7 * day), &(30 * day));
+    assert_eq!(
+        setup.escrow.get_deadline_policy(),
+        DeadlinePolicy {
+            min_deadline_offset: 7 * day,
+            max_deadline_offset: 30 * day,
+        }
+    );
+    assert_eq!(setup.escrow.get_limits().max_deadline_horizon, 30 * day);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1000, &(now + day)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1000, &(now + 31 * day)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline: now + day,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &(now + 7 * day));
+    assert_eq!(setup.escrow.get_escrow_info(&1).deadline, now + 7 * day);
+}
+
+#[test]
+fn test_refund_clamped_to_contract_balance() {
+    use soroban_sdk::{testutils::Events as _, TryFromVal};
+
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    // Leave the contract under-backed
+    let other = Address::generate(&setup.env);
+    setup.escrow.pause();
+    setup
+        .escrow
+        .emergency_distribute(&vec![&setup.env, (other, 400)]);
+    setup.escrow.unpause();
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let before = setup.token.balance(&setup.depositor);
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None);
+
+    let shortfall = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find_map(|(_, topics, data)| {
+            let topic = Symbol::try_from_val(&setup.env, &topics.get(0)?).ok()?;
+            (topic == symbol_short!("rf_short"))
+                .then(|| events::RefundShortfall::try_from_val(&setup.env, &data).unwrap())
+        })
+        .unwrap();
+    assert_eq!(shortfall.requested, 1000);
+    assert_eq!(shortfall.refunded, 600);
+    assert_eq!(shortfall.shortfall, 400);
+
+    assert_eq!(setup.token.balance(&setup.depositor) - before, 600);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 400);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+
+    // Nothing left to pay out
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund(&1, &None, &None, &RefundMode::Full, &None),
+        Err(Ok(Error::InsufficientFunds))
+    );
+}
+
+#[test]
+fn test_refund_clamp_leaves_other_escrows_backed() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.env.ledger().set_timestamp(1100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+
+    let other = Address::generate(&setup.env);
+    setup.escrow.pause();
+    setup
+        .escrow
+        .emergency_distribute(&vec![&setup.env, (other, 400)]);
+    setup.escrow.unpause();
+
+    // The refunded escrow absorbs the shortfall instead of draining bounty 2
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let before = setup.token.balance(&setup.depositor);
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None);
+    assert_eq!(setup.token.balance(&setup.depositor) - before, 600);
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 400);
+
+    setup.escrow.release_funds(&2, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_lock_funds_auto_id() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    let first = setup
+        .escrow
+        .lock_funds_auto_id(&setup.depositor, &100, &deadline);
+    assert_eq!(first, 1);
+
+    // Explicitly chosen ids are skipped
+    setup.env.ledger().set_timestamp(1100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+    setup.env.ledger().set_timestamp(1200);
+    let second = setup
+        .escrow
+        .lock_funds_auto_id(&setup.depositor, &250, &deadline);
+    assert_eq!(second, 3);
+    assert_eq!(setup.escrow.get_escrow_info(&second).amount, 250);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&second).depositor,
+        setup.depositor
+    );
+}
+
+#[test]
+fn test_status_to_string_matches_variant_names() {
+    use crate::indexed::indexing_integration::status_to_string;
+
+    let env = Env::default();
+    let cases = [
+        (EscrowStatus::Locked, "Locked"),
+        (EscrowStatus::Released, "Released"),
+        (EscrowStatus::Refunded, "Refunded"),
+        (EscrowStatus::PartiallyRefunded, "PartiallyRefunded"),
+        (EscrowStatus::PartiallyReleased, "PartiallyReleased"),
+    ];
+    for (status, name) in cases {
+        assert_eq!(
+            status_to_string(&env, &status),
+            String::from_str(&env, name)
+        );
+    }
+}
+
+#[test]
+fn test_batch_lock_total_overflow_rejected() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let half = i128::MAX / 2 + 1;
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: half,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: half,
+            deadline,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_timelocked_release_request() {
+    use soroban_sdk::{testutils::Events as _, IntoVal};
+
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.set_release_timelock(&3600);
+    assert_eq!(setup.escrow.get_release_timelock(), 3600);
+
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ReleaseNotRequested))
+    );
+    // The timelock can't be skipped through release_funds
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::ReleaseNotRequested))
+    );
+
+    let request = setup.escrow.request_release(&1, &setup.contributor);
+    let (_, topics, _) = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rel_req"), 1u64).into_val(&setup.env)
+    );
+    assert_eq!(
+        request,
+        ReleaseRequest {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+            requested_by: setup.admin.clone(),
+            requested_at: 1000,
+            executable_after: 1000 + 3600,
+        }
+    );
+    assert_eq!(setup.escrow.get_release_request(&1), Some(request));
+
+    setup.env.ledger().set_timestamp(1000 + 3599);
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ReleaseTimelocked))
+    );
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::ReleaseTimelocked))
+    );
+    let other = Address::generate(&setup.env);
+    assert_eq!(
+        setup
+            .escrow
+            .try_batch_release_partial(&vec![&setup.env, (1u64, other, 100)]),
+        Err(Ok(Error::ReleaseNotRequested))
+    );
+
+    setup.env.ledger().set_timestamp(1000 + 3600);
+    setup.escrow.execute_release(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(setup.escrow.get_release_request(&1), None);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_cancel_release_request() {
+    use soroban_sdk::{testutils::Events as _, IntoVal};
+
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    assert_eq!(
+        setup.escrow.try_cancel_release_request(&1),
+        Err(Ok(Error::ReleaseNotRequested))
+    );
+    setup.escrow.request_release(&1, &setup.contributor);
+    setup.escrow.cancel_release_request(&1);
+    let (_, topics, _) = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rel_cncl"), 1u64).into_val(&setup.env)
+    );
+
+    assert_eq!(setup.escrow.get_release_request(&1), None);
+    assert_eq!(
+        setup.escrow.try_execute_release(&1),
+        Err(Ok(Error::ReleaseNotRequested))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_release_request_cleared_on_settlement() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.env.ledger().set_timestamp(1100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+
+    // Without a timelock a direct release still consumes the request
+    setup.escrow.request_release(&1, &setup.contributor);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.get_release_request(&1), None);
+
+    // A full refund drops a request that can no longer be executed
+    setup.escrow.request_release(&2, &setup.contributor);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &None, &None, &RefundMode::Full, &None);
+    assert_eq!(setup.escrow.get_release_request(&2), None);
+}
+
+#[test]
+fn test_batch_admin_ops() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let mut items = Vec::new(&setup.env);
+    for bounty_id in 1..=3u64 {
+        items.push_back(LockFundsItem {
+            bounty_id,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline,
+        });
+    }
+    setup.escrow.batch_lock_funds(&items);
+
+    let ops = vec![
+        &setup.env,
+        AdminOp::Release(1, setup.contributor.clone()),
+        AdminOp::ApproveRefund(2, 400, setup.depositor.clone(), RefundMode::Partial, None),
+        AdminOp::Freeze(3),
+    ];
+    assert_eq!(setup.escrow.batch_admin_ops(&ops), 3);
+
+    // The admin signed exactly once for the whole batch
+    let auths = setup.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, setup.admin);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert!(setup.escrow.get_refund_eligibility(&2).3.is_some());
+    assert!(setup.escrow.is_bounty_frozen(&3));
+
+    // Atomic: a failing op undoes the ones before it
+    let ops = vec![
+        &setup.env,
+        AdminOp::Unfreeze(3),
+        AdminOp::Release(1, setup.contributor.clone()),
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_admin_ops(&ops),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert!(setup.escrow.is_bounty_frozen(&3));
+
+    assert_eq!(
+        setup.escrow.try_batch_admin_ops(&vec![&setup.env]),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+#[cfg(not(feature = "fees"))]
+fn test_no_fees_lock_release_refund() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1000);
+    assert_eq!(escrow.gross_amount, 1000);
+    assert_eq!(escrow.lock_fee_paid, 0);
+
+    let preview = setup.escrow.preview_payout(&1);
+    assert_eq!((preview.release_fee, preview.net), (0, 1000));
+    assert_eq!(preview.fee_recipient, None);
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+
+    setup.env.ledger().set_timestamp(deadline - 500);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &None, &None, &RefundMode::Full, &None::<String>);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 1000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_auto_refund_reports_clamped_amount() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    // Leave the contract under-backed
+    setup.escrow.pause();
+    setup
+        .escrow
+        .emergency_distribute(&vec![&setup.env, (Address::generate(&setup.env), 400)]);
+    setup.escrow.unpause();
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let before = setup.token.balance(&setup.depositor);
+    assert_eq!(setup.escrow.auto_refund(&1), 600);
+    assert_eq!(setup.token.balance(&setup.depositor) - before, 600);
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 400);
+}
+
+#[test]
+fn test_batch_release_best_effort_reports_failed_transfer() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &600, &deadline);
+    setup.env.ledger().set_timestamp(1100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &400, &deadline);
+
+    // Leave the contract able to pay only the first item
+    setup.escrow.pause();
+    setup
+        .escrow
+        .emergency_distribute(&vec![&setup.env, (Address::generate(&setup.env), 300)]);
+    setup.escrow.unpause();
+
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    let result = setup.escrow.batch_release_best_effort(&items);
+    assert_eq!(result.successful, vec![&setup.env, 1u64]);
+    assert_eq!(
+        result.failed,
+        vec![
+            &setup.env,
+            BatchItemError {
+                index: 1,
+                bounty_id: 2,
+                error_code: Error::TransferFailed as u32,
+            }
+        ]
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 600);
+
+    // The failed item is left untouched
+    let escrow = setup.escrow.get_escrow_info(&2);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 400);
+    assert_eq!(setup.escrow.get_total_locked(), 400);
+}
+
+#[test]
+fn test_clear_stuck_reentrancy_lock() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    assert!(!setup.escrow.is_reentrancy_locked());
+
+    // Simulate a guard left behind by an earlier failure
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+    });
+    assert!(setup.escrow.is_reentrancy_locked());
+    assert!(setup
+        .escrow
+        .try_release_funds(&1, &setup.contributor)
+        .is_err());
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert!(setup
+        .escrow
+        .try_refund(&1, &None, &None, &RefundMode::Full, &None)
+        .is_err());
+
+    // Views don't check the guard
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 1000);
+    assert_eq!(
+        setup.escrow.get_escrow_summary(&1).status,
+        EscrowStatus::Locked
+    );
+
+    setup.escrow.clear_reentrancy_lock();
+    assert!(!setup.escrow.is_reentrancy_locked());
+    setup.escrow.release_funds(&1, &setup.contributor);
+}
+
+#[test]
+fn test_reassign_bounty() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline);
+    let balance_before = setup.token.balance(&setup.escrow_address);
+
+    // Target id must be free
+    let res = setup.escrow.try_reassign_bounty(&1, &2);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+    let res = setup.escrow.try_reassign_bounty(&7, &8);
+    assert_eq!(res, Err(Ok(Error::BountyNotFound)));
+
+    setup.escrow.reassign_bounty(&1, &10);
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    let moved = setup.escrow.get_escrow_info(&10);
+    assert_eq!(moved.amount, 1000);
+    assert_eq!(moved.status, EscrowStatus::Locked);
+    assert_eq!(setup.token.balance(&setup.escrow_address), balance_before);
+
+    // The new id behaves like any locked bounty
+    setup.escrow.release_funds(&10, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+
+    // Only locked escrows can be moved
+    let res = setup.escrow.try_reassign_bounty(&10, &11);
+    assert_eq!(res, Err(Ok(Error::FundsNotLocked)));
+}
+
+#[test]
+fn test_refund_history_cap_emits_overflow() {
+    use soroban_sdk::{testutils::Events as _, IntoVal};
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.set_max_refund_history(&2);
+    assert_eq!(setup.escrow.get_max_refund_history(), 2);
+    setup.env.ledger().set_timestamp(deadline + 1);
+
+    for _ in 0..3 {
+        setup
+            .escrow
+            .refund(&1, &Some(100), &None, &RefundMode::Partial, &None::<String>);
+    }
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+    let overflow_topic: soroban_sdk::Val = symbol_short!("rf_over").into_val(&setup.env);
+    let overflowed = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| topics.get(0).is_some_and(|t| t.shallow_eq(&overflow_topic)))
+        .count();
+
+    // Refunds keep working past the cap, history stays bounded
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 2);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+    assert_eq!(overflowed, 2);
+}
+
+#[test]
+fn test_get_refund_history_paged() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    for amount in [10i128, 20, 30, 40, 50] {
+        setup.escrow.refund(
+            &1,
+            &Some(amount),
+            &None,
+            &RefundMode::Partial,
+            &None::<String>,
+        );
+    }
+
+    let page0 = setup.escrow.get_refund_history_paged(&1, &0, &2);
+    assert_eq!(page0.len(), 2);
+    assert_eq!(page0.get(0).unwrap().amount, 10);
+    assert_eq!(page0.get(1).unwrap().amount, 20);
+
+    let page2 = setup.escrow.get_refund_history_paged(&1, &2, &2);
+    assert_eq!(page2.len(), 1);
+    assert_eq!(page2.get(0).unwrap().amount, 50);
+
+    assert!(setup.escrow.get_refund_history_paged(&1, &3, &2).is_empty());
+    assert!(setup
+        .escrow
+        .get_refund_history_paged(&1, &u32::MAX, &u32::MAX)
+        .is_empty());
+    assert_eq!(
+        setup.escrow.try_get_refund_history_paged(&9, &0, &2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_lock_funds_scheduled_gates_release_and_refund() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let active_from = 2000;
+    let deadline = 3000;
+
+    let res = setup.escrow.try_lock_funds_scheduled(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &(deadline + 1),
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidDeadline)));
+
+    setup
+        .escrow
+        .lock_funds_scheduled(&setup.depositor, &1, &1000, &deadline, &active_from);
+    // Tokens move at lock time
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+    assert_eq!(setup.escrow.get_escrow_info(&1).active_from, active_from);
+
+    let res = setup.escrow.try_release_funds(&1, &setup.contributor);
+    assert_eq!(res, Err(Ok(Error::NotYetActive)));
+    setup.escrow.approve_refund(
+        &1,
+        &1000,
+        &setup.depositor,
+        &RefundMode::Full,
+        &None::<String>,
+    );
+    let res = setup
+        .escrow
+        .try_refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+    assert_eq!(res, Err(Ok(Error::NotYetActive)));
+
+    setup.env.ledger().set_timestamp(active_from);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_release_and_relock() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline);
+    let depositor_before = setup.token.balance(&setup.depositor);
+
+    // Successor id already taken: the release is rolled back too
+    let res =
+        setup
+            .escrow
+            .try_release_and_relock(&1, &setup.contributor, &2, &800, &(deadline + 1000));
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    setup
+        .escrow
+        .release_and_relock(&1, &setup.contributor, &3, &800, &(deadline + 1000));
+
+    let auths = setup.env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == setup.admin));
+    assert!(auths.iter().any(|(addr, _)| *addr == setup.depositor));
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    let next = setup.escrow.get_escrow_info(&3);
+    assert_eq!(next.status, EscrowStatus::Locked);
+    assert_eq!(next.depositor, setup.depositor);
+    assert_eq!(next.amount, 800);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        depositor_before - 800
+    );
+}
+
+#[test]
+fn test_get_bounty_summary() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    let summary = setup.escrow.get_bounty_summary(&1);
+    assert_eq!(summary.depositor, setup.depositor);
+    assert_eq!(summary.remaining_amount, 1000);
+    assert_eq!(summary.status, EscrowStatus::Locked);
+    assert!(!summary.deadline_passed);
+    assert!(!summary.has_valid_approval);
+    assert!(!summary.is_refundable);
+
+    setup.escrow.approve_refund(
+        &1,
+        &400,
+        &setup.depositor,
+        &RefundMode::Partial,
+        &None::<String>,
+    );
+    let summary = setup.escrow.get_bounty_summary(&1);
+    assert!(summary.has_valid_approval);
+    assert!(summary.is_refundable);
+
+    // Frozen bounties can't be refunded even with an approval
+    setup.escrow.freeze_bounty(&1);
+    assert!(!setup.escrow.get_bounty_summary(&1).is_refundable);
+    setup.escrow.unfreeze_bounty(&1);
+
+    setup.env.ledger().set_timestamp(deadline);
+    let summary = setup.escrow.get_bounty_summary(&1);
+    assert!(summary.deadline_passed);
+    assert!(summary.is_refundable);
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    let summary = setup.escrow.get_bounty_summary(&1);
+    assert_eq!(summary.status, EscrowStatus::Released);
+    assert!(!summary.is_refundable);
+
+    assert_eq!(
+        setup.escrow.try_get_bounty_summary(&9),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_lock_funds_insufficient_balance_returns_transfer_failed() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let poor_depositor = Address::generate(&setup.env);
+    setup.token_admin.mint(&poor_depositor, &100);
+
+    let res = setup
+        .escrow
+        .try_lock_funds(&poor_depositor, &1, &1000, &deadline);
+    assert_eq!(res, Err(Ok(Error::TransferFailed)));
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: poor_depositor.clone(),
+            amount: 1000,
+            deadline,
+        },
+    ];
+    let res = setup.escrow.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Ok(Error::TransferFailed)));
+    assert_eq!(setup.token.balance(&poor_depositor), 100);
+}
+
+#[test]
+fn test_refund_destination_set_by_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let operator_wallet = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    assert_eq!(setup.escrow.get_refund_destination(&1), None);
+
+    setup.escrow.set_refund_destination(&1, &operator_wallet);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.depositor,
+        "destination must be authorized by the depositor"
+    );
+    assert_eq!(
+        setup.escrow.get_refund_destination(&1),
+        Some(operator_wallet.clone())
+    );
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_refund_destination(&9, &operator_wallet),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+
+    assert_eq!(setup.token.balance(&operator_wallet), 1000);
+    assert_eq!(setup.token.balance(&setup.depositor), 999_000);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().recipient,
+        operator_wallet
+    );
+}
+
+#[test]
+fn test_transfer_depositor_moves_refund_claim() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let buyer = Address::generate(&setup.env);
+    let operator_wallet = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.set_refund_destination(&1, &operator_wallet);
+
+    setup.escrow.transfer_depositor(&1, &buyer);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.depositor,
+        "transfer must be authorized by the current depositor"
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&1).depositor, buyer);
+    assert_eq!(setup.escrow.get_refund_destination(&1), None);
+
+    setup.env.as_contract(&setup.escrow_address, || {
+        let indexed = indexed::indexed_storage::get_bounty(&setup.env, 1).unwrap();
+        assert_eq!(indexed.depositor, buyer);
+        let storage = setup.env.storage().persistent();
+        assert!(!storage.has(&(
+            indexed::indexed_storage::DEPOSITOR_INDEX,
+            setup.depositor.clone(),
+            1u64
+        )));
+        assert!(storage.has(&(
+            indexed::indexed_storage::DEPOSITOR_INDEX,
+            buyer.clone(),
+            1u64
+        )));
+    });
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None, &None, &RefundMode::Full, &None::<String>);
+    assert_eq!(setup.token.balance(&buyer), 1000);
+    assert_eq!(setup.token.balance(&operator_wallet), 0);
+
+    // Only live escrows can change hands
+    assert_eq!(
+        setup.escrow.try_transfer_depositor(&1, &setup.depositor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        setup.escrow.try_transfer_depositor(&9, &buyer),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    // Once part of the bounty went to a contributor it can't change hands
+    setup.env.ledger().set_timestamp(deadline + 100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &(deadline + 1000));
+    setup
+        .escrow
+        .batch_release_partial(&vec![&setup.env, (2u64, setup.contributor.clone(), 400)]);
+    assert_eq!(
+        setup.escrow.try_transfer_depositor(&2, &buyer),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_transfer_depositor_moves_client_ref() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let buyer = Address::generate(&setup.env);
+    let client_ref = BytesN::from_array(&setup.env, &[7u8; 32]);
+
+    setup
+        .escrow
+        .lock_funds_with_ref(&setup.depositor, &1, &1000, &deadline, &client_ref);
+
+    setup.escrow.pause();
+    assert_eq!(
+        setup.escrow.try_transfer_depositor(&1, &buyer),
+        Err(Ok(Error::ContractPaused))
+    );
+    setup.escrow.unpause();
+
+    setup.escrow.transfer_depositor(&1, &buyer);
+    assert_eq!(setup.escrow.get_escrow_by_ref(&buyer, &client_ref).0, 1);
+    assert_eq!(
+        setup
+            .escrow
+            .try_get_escrow_by_ref(&setup.depositor, &client_ref),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_auto_refund_after_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    assert_eq!(
+        setup.escrow.try_auto_refund(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &Some(300), &None, &RefundMode::Partial, &None::<String>);
+    assert_eq!(setup.escrow.auto_refund(&1), 700);
+
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    assert_eq!(
+        setup.escrow.try_auto_refund(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        setup.escrow.try_auto_refund(&9),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_archive_escrow_after_retention() {
+    use soroban_sdk::{testutils::Events as _, IntoVal, TryFromVal};
+
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let stranger = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.env.ledger().set_timestamp(1_100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.get_archive_retention(), 30 * 24 * 60 * 60);
+
+    assert_eq!(
+        setup.escrow.try_archive_escrow(&setup.depositor, &1),
+        Err(Ok(Error::RetentionNotElapsed))
+    );
+    assert_eq!(
+        setup.escrow.try_archive_escrow(&setup.depositor, &2),
+        Err(Ok(Error::EscrowNotFinal))
+    );
+    assert_eq!(
+        setup.escrow.try_archive_escrow(&stranger, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    setup.escrow.set_archive_retention(&100);
+    setup.env.ledger().set_timestamp(1_200);
+    setup.escrow.archive_escrow(&setup.depositor, &1);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.depositor,
+        "archiving must be authorized by the caller"
+    );
+
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyArchived))
+    );
+    assert_eq!(
+        setup.escrow.try_get_escrow_info(&3),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .count_by_status(&indexed::indexed_storage::BountyStatus::Released),
+        0
+    );
+    setup.env.as_contract(&setup.escrow_address, || {
+        assert!(indexed::indexed_storage::get_bounty(&setup.env, 1).is_none());
+    });
+
+    let (_, topics, data) = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("archived"), 1u64).into_val(&setup.env)
+    );
+    let archived = events::EscrowArchived::try_from_val(&setup.env, &data).unwrap();
+    assert_eq!(archived.amount, 1000);
+    assert_eq!(archived.final_status, EscrowStatus::Released);
+    assert_eq!(archived.settled_at, 1_100);
+    assert_eq!(archived.archived_by, setup.depositor);
+
+    assert_eq!(
+        setup.escrow.try_archive_escrow(&setup.admin, &1),
+        Err(Ok(Error::BountyArchived))
+    );
+}
+
+#[test]
+fn test_archived_bounty_id_not_reused() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.escrow.set_archive_retention(&0);
+    setup.escrow.archive_escrow(&setup.depositor, &1);
+
+    setup.env.ledger().set_timestamp(1_100);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1000, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::BountyExists))
+    );
+    // Auto-assigned ids skip the archived id too
+    assert_eq!(
+        setup
+            .escrow
+            .lock_funds_auto_id(&setup.depositor, &1000, &deadline),
+        2
+    );
+}
+
+#[test]
+fn test_get_tvl_by_token() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other_token = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline);
+    assert_eq!(setup.escrow.get_tvl_by_token(&setup.token.address), 1500);
+    assert_eq!(setup.escrow.get_tvl_by_token(&other_token), 0);
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.get_tvl_by_token(&setup.token.address), 500);
+}
+
+#[test]
+fn test_release_uses_token_from_lock_time() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    // There is no way to swap the escrow token after init
+    let (other_token, _) = create_token_contract(&setup.env, &setup.admin);
+    assert_eq!(
+        try_init_escrow(&setup.escrow, &setup.admin, &other_token.address),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(other_token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_escrow_counts() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &10_000);
+
+    assert_eq!(setup.escrow.get_escrow_count(), 0);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: other.clone(),
+            amount: 1000,
+            deadline,
+        },
+    ];
+    setup.escrow.batch_lock_funds(&items);
+
+    assert_eq!(setup.escrow.get_escrow_count(), 3);
+    assert_eq!(setup.escrow.get_depositor_escrow_count(&setup.depositor), 2);
+    assert_eq!(setup.escrow.get_depositor_escrow_count(&other), 1);
+
+    // Settling keeps the counts; handing over ownership moves them
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.escrow.transfer_depositor(&2, &other);
+    assert_eq!(setup.escrow.get_escrow_count(), 3);
+    assert_eq!(setup.escrow.get_depositor_escrow_count(&setup.depositor), 1);
+    assert_eq!(setup.escrow.get_depositor_escrow_count(&other), 2);
+}
+
+#[test]
+fn test_query_filter_builder_and_validation() {
+    use crate::indexed::contract_query_interface::{
+        EscrowQueryContract, EscrowQueryContractClient,
+    };
+    use crate::indexed::indexed_storage::{BountyStatus, QueryFilter};
+
+    let env = Env::default();
+    let depositor = Address::generate(&env);
+    let filter = QueryFilter::builder()
+        .status(BountyStatus::Locked)
+        .depositor(depositor.clone())
+        .min_amount(100)
+        .max_amount(100)
+        .deadline_to(500)
+        .build();
+    assert_eq!(filter.status, BountyStatus::Locked);
+    assert_eq!(filter.depositor, Some(depositor));
+    assert_eq!(filter.from_timestamp, None);
+    assert_eq!(filter.validate(), Ok(()));
+
+    let inverted = [
+        QueryFilter::builder().min_amount(10).max_amount(9).build(),
+        QueryFilter::builder()
+            .created_from(10)
+            .created_to(9)
+            .build(),
+        QueryFilter::builder()
+            .deadline_from(10)
+            .deadline_to(9)
+            .build(),
+    ];
+    for filter in inverted.iter() {
+        assert_eq!(filter.validate(), Err(Error::InvalidQueryFilter));
+    }
+
+    let query =
+        EscrowQueryContractClient::new(&env, &env.register_contract(None, EscrowQueryContract));
+    assert_eq!(
+        query.try_query_bounties(&inverted[0], &0, &10).err(),
+        Some(Ok(Error::InvalidQueryFilter))
+    );
+}
+
+#[test]
+fn test_deadline_alert_subscriptions() {
+    use crate::indexed::{NotificationEvent, NotificationType};
+    use soroban_sdk::{testutils::Events as _, TryFromVal};
+
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = 1000 + 3 * 24 * 60 * 60;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup
+        .escrow
+        .subscribe_deadline_alerts(&1, &setup.contributor);
+    setup.escrow.subscribe_deadline_alerts(&1, &setup.depositor);
+    setup
+        .escrow
+        .subscribe_deadline_alerts(&1, &setup.contributor);
+    assert_eq!(
+        setup.escrow.get_deadline_subscribers(&1),
+        vec![
+            &setup.env,
+            setup.contributor.clone(),
+            setup.depositor.clone()
+        ]
+    );
+
+    // Too early: nothing is sent
+    assert_eq!(setup.escrow.emit_due_notifications(&1), 0);
+
+    setup.env.ledger().set_timestamp(deadline - 3600);
+    assert_eq!(setup.escrow.emit_due_notifications(&1), 2);
+    let mut recipients = Vec::new(&setup.env);
+    for (_, topics, data) in setup.env.events().all().iter() {
+        if Symbol::try_from_val(&setup.env, &topics.get(0).unwrap()) != Ok(symbol_short!("notify"))
+        {
+            continue;
+        }
+        let event = NotificationEvent::try_from_val(&setup.env, &data).unwrap();
+        assert_eq!(
+            event.notification_type,
+            NotificationType::DeadlineApproaching
+        );
+        assert_eq!(event.bounty_id, 1);
+        recipients.push_back(event.recipient);
+    }
+    assert_eq!(
+        recipients,
+        vec![
+            &setup.env,
+            setup.contributor.clone(),
+            setup.depositor.clone()
+        ]
+    );
+
+    // Alerts go out once per deadline
+    assert_eq!(setup.escrow.emit_due_notifications(&1), 0);
+}
+
+#[test]
+fn test_reassign_bounty_keeps_deadline_subscribers() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = 1000 + 3 * 24 * 60 * 60;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .subscribe_deadline_alerts(&1, &setup.contributor);
+
+    setup.escrow.reassign_bounty(&1, &2);
+    assert_eq!(setup.escrow.get_deadline_subscribers(&1).len(), 0);
+    assert_eq!(
+        setup.escrow.get_deadline_subscribers(&2),
+        vec![&setup.env, setup.contributor.clone()]
+    );
+
+    setup.env.ledger().set_timestamp(deadline - 3600);
+    assert_eq!(setup.escrow.emit_due_notifications(&2), 1);
+}
+
+#[test]
+fn test_monitoring_can_be_disabled() {
+    use soroban_sdk::{testutils::Events as _, TryFromVal};
+
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let operation_count = || {
+        setup.env.as_contract(&setup.escrow_address, || {
+            monitoring::get_analytics(&setup.env).operation_count
+        })
+    };
+    let metric_events = || {
+        setup
+            .env
+            .events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| {
+                Symbol::try_from_val(&setup.env, &topics.get(0).unwrap())
+                    == Ok(symbol_short!("metric"))
+            })
+            .count()
+    };
+
+    assert!(setup.escrow.is_monitoring_enabled());
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    let tracked = operation_count();
+    assert!(tracked > 0);
+
+    setup.escrow.set_monitoring_enabled(&false);
+    assert!(!setup.escrow.is_monitoring_enabled());
+    let metrics = metric_events();
+    setup.env.ledger().set_timestamp(1100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    assert_eq!(metric_events(), metrics);
+    setup.escrow.release_funds(&2, &setup.contributor);
+    assert_eq!(metric_events(), metrics);
+
+    // Only the bookkeeping is skipped
+    assert_eq!(operation_count(), tracked);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Released
+    );
+}
+
+#[test]
+fn test_sunset_permanently_disables_locks() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    assert!(!setup.escrow.is_sunset());
+
+    setup.escrow.sunset();
+    assert!(setup.escrow.is_sunset());
+
+    let res = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &2, &1000, &deadline);
+    assert_eq!(res, Err(Ok(Error::LocksDisabled)));
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 1000,
+            deadline,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::LocksDisabled))
+    );
+
+    // Pause/unpause doesn't lift the sunset
+    setup.escrow.pause();
+    setup.escrow.unpause();
+    let res = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &2, &1000, &deadline);
+    assert_eq!(res, Err(Ok(Error::LocksDisabled)));
+
+    // Existing escrows still drain
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_event_topics_cover_emitted_events() {
+    use soroban_sdk::{testutils::Events as _, TryFromVal};
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let topics = setup.escrow.get_event_topics();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &None, &None, &RefundMode::Full, &None::<String>);
+    setup.escrow.pause();
+    setup.escrow.unpause();
+
+    let mut seen = 0;
+    for (contract, event_topics, _) in setup.env.events().all().iter() {
+        if contract != setup.escrow_address {
+            continue;
+        }
+        let first = Symbol::try_from_val(&setup.env, &event_topics.get(0).unwrap()).unwrap();
+        assert!(topics.contains(&first), "undeclared event topic");
+        seen += 1;
+    }
+    assert!(seen > 0);
+}
+
+#[test]
+fn test_release_requires_m_of_n_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let approver_a = Address::generate(&env);
+    let approver_b = Address::generate(&env);
+    let approver_c = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let (escrow, _) = create_escrow_contract(&env);
+    token_admin.mint(&depositor, &10_000);
+
+    let approvers = vec![
+        &env,
+        approver_a.clone(),
+        approver_b.clone(),
+        approver_c.clone(),
+    ];
+    #[cfg(feature = "fees")]
+    let too_many = escrow.try_init_with_approvers(&admin, &token.address, &None, &approvers, &4);
+    #[cfg(not(feature = "fees"))]
+    let too_many = escrow.try_init_with_approvers(&admin, &token.address, &approvers, &4);
+    assert_eq!(too_many, Err(Ok(Error::InvalidApprovalThreshold)));
+    #[cfg(feature = "fees")]
+    escrow.init_with_approvers(&admin, &token.address, &None, &approvers, &2);
+    #[cfg(not(feature = "fees"))]
+    escrow.init_with_approvers(&admin, &token.address, &approvers, &2);
+    assert_eq!(escrow.get_release_approvals_required(), 2);
+    assert_eq!(escrow.get_release_approvers(), approvers);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &1000, &deadline);
+
+    assert_eq!(
+        escrow.try_release_funds(&1, &contributor),
+        Err(Ok(Error::ReleaseNotApproved))
+    );
+    assert_eq!(
+        escrow.try_approve_release(&outsider, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(escrow.approve_release(&approver_a, &1), 1);
+    // Repeat approvals from the same admin don't count twice
+    assert_eq!(escrow.approve_release(&approver_a, &1), 1);
+    assert_eq!(
+        escrow.try_release_funds(&1, &contributor),
+        Err(Ok(Error::ReleaseNotApproved))
+    );
+
+    assert_eq!(escrow.approve_release(&approver_c, &1), 2);
+    escrow.release_funds(&1, &contributor);
+    assert_eq!(token.balance(&contributor), 1000);
+    assert_eq!(escrow.get_release_approvals(&1).len(), 0);
+
+    // Batch releases honour the same threshold
+    escrow.lock_funds(&depositor, &2, &1000, &deadline);
+    let items = vec![
+        &env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor.clone(),
+        },
+    ];
+    assert_eq!(
+        escrow.try_batch_release_funds(&items),
+        Err(Ok(Error::ReleaseNotApproved))
+    );
+    escrow.approve_release(&approver_a, &2);
+    escrow.approve_release(&approver_b, &2);
+    assert_eq!(escrow.batch_release_funds(&items), 1);
+    assert_eq!(token.balance(&contributor), 2000);
+}
+
+#[test]
+fn test_escrow_updated_seq_tracks_changes() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.env.ledger().with_mut(|li| {
+        li.sequence_number = 10;
+        li.timestamp = 100;
+    });
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.updated_seq, 10);
+    assert_eq!(escrow.updated_at, 100);
+
+    // Reads don't bump it
+    setup.env.ledger().with_mut(|li| li.sequence_number = 11);
+    assert_eq!(setup.escrow.get_escrow_info(&1).updated_seq, 10);
+
+    setup.env.ledger().with_mut(|li| {
+        li.sequence_number = 12;
+        li.timestamp = 200;
+    });
+    setup.escrow.release_funds(&1, &setup.contributor);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.updated_seq, 12);
+    assert_eq!(escrow.updated_at, 200);
+}
+
+#[test]
+fn test_count_by_status() {
+    use crate::indexed::indexed_storage::BountyStatus;
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    for id in 1..=3 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &id, &1000, &deadline);
+    }
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Locked), 3);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Released), 0);
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &None, &None, &RefundMode::Full, &None::<String>);
+
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Locked), 1);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Released), 1);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Refunded), 1);
+
+    // Moving a bounty to a new id doesn't change the totals
+    setup.escrow.reassign_bounty(&3, &30);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Locked), 1);
+}
+
+#[test]
+fn test_get_escrows_due_before() {
+    let setup = TestSetup::new();
+    let day = 86_400u64;
+
+    // Locked out of deadline order, spread over several days
+    let deadlines = [
+        (1u64, 3 * day + 10),
+        (2, day + 500),
+        (3, 3 * day + 5),
+        (4, 10 * day),
+        (5, day + 100),
+    ];
+    for (bounty_id, deadline) in deadlines {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+    }
+
+    assert_eq!(
+        setup.escrow.get_escrows_due_before(&(3 * day + 5), &10),
+        vec![&setup.env, 5u64, 2, 3]
+    );
+    assert_eq!(
+        setup.escrow.get_escrows_due_before(&(20 * day), &2),
+        vec![&setup.env, 5u64, 2]
+    );
+    assert_eq!(setup.escrow.get_escrows_due_before(&day, &10).len(), 0);
+
+    // Settled escrows drop out; reassigned ones keep their place
+    setup.escrow.release_funds(&5, &setup.contributor);
+    setup.escrow.reassign_bounty(&3, &30);
+    assert_eq!(
+        setup.escrow.get_escrows_due_before(&(20 * day), &10),
+        vec![&setup.env, 2u64, 30, 1, 4]
+    );
+
+    setup.env.ledger().set_timestamp(10 * day);
+    setup
+        .escrow
+        .refund(&4, &None, &None, &RefundMode::Full, &None::<String>);
+    assert_eq!(
+        setup.escrow.get_escrows_due_before(&(20 * day), &10),
+        vec![&setup.env, 2u64, 30, 1]
+    );
+}
+
+#[test]
+fn test_reindex_rebuilds_missing_index_entries() {
+    use crate::indexed::indexed_storage::{self, BountyStatus};
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    setup.escrow.release_funds(&2, &setup.contributor);
+
+    // Simulate escrows that were never indexed
+    setup.env.as_contract(&setup.escrow_address, || {
+        indexed_storage::remove_bounty(&setup.env, 1);
+        indexed_storage::remove_bounty(&setup.env, 2);
+    });
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Locked), 0);
+
+    assert_eq!(
+        setup.escrow.try_reindex(&vec![&setup.env, 1, 99]),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    assert_eq!(setup.escrow.reindex(&vec![&setup.env, 1, 2]), 2);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Locked), 1);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Released), 1);
+
+    // Repeating it is harmless
+    setup.escrow.reindex(&vec![&setup.env, 1, 2]);
+    assert_eq!(setup.escrow.count_by_status(&BountyStatus::Locked), 1);
+    setup.env.as_contract(&setup.escrow_address, || {
+        let bounty = indexed_storage::get_bounty(&setup.env, 1).unwrap();
+        assert_eq!(bounty.depositor, setup.depositor);
+        assert_eq!(bounty.amount, 1000);
+    });
+}
+
+#[test]
+fn test_recipient_policy_rejects_self_and_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+    assert_eq!(
+        setup.escrow.get_recipient_policy(),
+        RecipientPolicy {
+            reject_self: false,
+            reject_depositor: false,
+        }
+    );
+
+    setup.escrow.set_recipient_policy(&RecipientPolicy {
+        reject_self: true,
+        reject_depositor: false,
+    });
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.escrow_address),
+        Err(Ok(Error::InvalidRecipient))
+    );
+    // Paying the depositor is still allowed
+    setup.escrow.release_funds(&1, &setup.depositor);
+
+    setup.escrow.set_recipient_policy(&RecipientPolicy {
+        reject_self: true,
+        reject_depositor: true,
+    });
+    assert_eq!(
+        setup.escrow.try_release_funds(&2, &setup.depositor),
+        Err(Ok(Error::InvalidRecipient))
+    );
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: setup.depositor.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&items),
+        Err(Ok(Error::InvalidRecipient))
+    );
+    let result = setup.escrow.batch_release_best_effort(&items);
+    assert_eq!(
+        result.failed.get(0).unwrap().error_code,
+        Error::InvalidRecipient as u32
+    );
+
+    setup.escrow.release_funds(&2, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_config_history_records_admin_changes() {
+    use soroban_sdk::xdr::FromXdr;
+
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_config_history(&0, &10).len(), 0);
+
+    setup.escrow.set_refund_grace_period(&3600);
+    setup.escrow.set_refund_grace_period(&7200);
+    setup.escrow.pause();
+    setup.escrow.unpause();
+    setup.escrow.set_large_lock_threshold(&Some(5_000));
+
+    let history = setup.escrow.get_config_history(&0, &10);
+    assert_eq!(history.len(), 5);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.field, symbol_short!("grace"));
+    assert_eq!(u64::from_xdr(&setup.env, &first.old).unwrap(), 0);
+    assert_eq!(u64::from_xdr(&setup.env, &first.new).unwrap(), 3600);
+    assert_eq!(first.by, setup.admin);
+
+    let second = history.get(1).unwrap();
+    assert_eq!(u64::from_xdr(&setup.env, &second.old).unwrap(), 3600);
+    assert_eq!(history.get(2).unwrap().field, symbol_short!("paused"));
+    assert_eq!(history.get(3).unwrap().field, symbol_short!("paused"));
+
+    let last = history.get(4).unwrap();
+    assert_eq!(last.field, symbol_short!("lg_lock"));
+    let new = Option::<i128>::from_xdr(&setup.env, &last.new).unwrap();
+    assert_eq!(new, Some(5_000));
+
+    // Pagination
+    let page = setup.escrow.get_config_history(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().field, symbol_short!("paused"));
+    assert_eq!(setup.escrow.get_config_history(&3, &2).len(), 0);
+
+    // Each change lives under its own key
+    setup.env.as_contract(&setup.escrow_address, || {
+        let storage = setup.env.storage();
+        assert_eq!(
+            storage
+                .instance()
+                .get::<_, u32>(&DataKey::ConfigHistoryCount),
+            Some(5)
+        );
+        assert!(storage.persistent().has(&DataKey::ConfigHistory(4)));
+        assert!(!storage.persistent().has(&DataKey::ConfigHistory(5)));
+    });
+}
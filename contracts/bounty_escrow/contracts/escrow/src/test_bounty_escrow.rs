@@ -1,11 +1,18 @@
 #![cfg(test)]
 
 use soroban_sdk::{
+    symbol_short,
     testutils::{Address as _, Events, Ledger},
-    token, vec, Address, Env,
+    token, vec, Address, Env, IntoVal, TryFromVal,
 };
 
+#[cfg(feature = "fees")]
+use crate::events::FeeCollected;
+use crate::events::{AntiAbuseConfigUpdated, EventVerbosity, FundsLocked, WhitelistChanged};
+use crate::test::init_escrow;
 use crate::{BountyEscrowContract, BountyEscrowContractClient};
+#[cfg(feature = "fees")]
+use crate::{Error, EscrowStatus, FeeConfig, FeeRoundingMode};
 
 fn create_test_env() -> (Env, BountyEscrowContractClient<'static>, Address) {
     let env = Env::default();
@@ -40,7 +47,7 @@ fn setup_bounty_with_schedule(
     release_timestamp: u64,
 ) {
     // Initialize contract
-    client.init(admin, token);
+    client.init(admin, token, &None);
 
     // Create and fund token
     let (_, token_client, token_admin) = create_token_contract(env, admin);
@@ -81,7 +88,7 @@ fn test_single_release_schedule() {
     let escrow = create_escrow_contract(&env);
 
     // Initialize escrow
-    escrow.init(&admin, &token_address);
+    init_escrow(&escrow, &admin, &token_address);
 
     // Mint tokens to admin
     token_admin.mint(&admin, &1000_0000000);
@@ -138,7 +145,7 @@ fn test_multiple_release_schedules() {
     let escrow = create_escrow_contract(&env);
 
     // Initialize escrow
-    escrow.init(&admin, &token_address);
+    init_escrow(&escrow, &admin, &token_address);
 
     // Mint tokens to admin
     token_admin.mint(&admin, &1000_0000000);
@@ -192,24 +199,27 @@ fn test_multiple_release_schedules() {
 
 #[test]
 fn test_init_event() {
-    let (env, client, _contract_id) = create_test_env();
+    let (env, client, contract_id) = create_test_env();
     let _employee = Address::generate(&env);
 
     let admin = Address::generate(&env);
-    let token = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &admin);
     let _depositor = Address::generate(&env);
     let _bounty_id = 1;
 
     env.mock_all_auths();
 
     // Initialize
-    client.init(&admin.clone(), &token.clone());
+    init_escrow(&client, &admin, &token);
 
-    // Get all events emitted
+    // Get all events emitted by the escrow (the token contract emits its own)
     let events = env.events().all();
+    let escrow_events = events
+        .iter()
+        .filter(|(emitter, _, _)| *emitter == contract_id);
 
     // Verify the event was emitted (1 init event + 2 monitoring events)
-    assert_eq!(events.len(), 3);
+    assert_eq!(escrow_events.count(), 3);
 }
 
 #[test]
@@ -230,7 +240,7 @@ fn test_lock_fund() {
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Initialize
-    client.init(&admin.clone(), &token.clone());
+    init_escrow(&client, &admin, &token);
 
     token_admin_client.mint(&depositor, &amount);
 
@@ -262,7 +272,7 @@ fn test_release_fund() {
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Initialize
-    client.init(&admin.clone(), &token.clone());
+    init_escrow(&client, &admin, &token);
 
     token_admin_client.mint(&depositor, &amount);
 
@@ -292,7 +302,7 @@ fn test_lock_fund_invalid_amount() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, _token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin.clone(), &token.clone());
+    init_escrow(&client, &admin, &token);
 
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 }
@@ -312,7 +322,7 @@ fn test_lock_fund_invalid_deadline() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin.clone(), &token.clone());
+    init_escrow(&client, &admin, &token);
     token_admin_client.mint(&depositor, &amount);
 
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
@@ -332,7 +342,7 @@ fn test_batch_lock_funds() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
 
     // Mint tokens for batch operations
     let total_amount = 5000i128;
@@ -385,7 +395,7 @@ fn test_batch_release_funds() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
 
     // Lock funds for multiple bounties
     let amount1 = 1000i128;
@@ -433,7 +443,7 @@ fn test_batch_lock_duplicate_bounty_id() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
     token_admin_client.mint(&depositor, &5000);
 
     // Create batch with duplicate bounty IDs
@@ -465,7 +475,7 @@ fn test_batch_lock_existing_bounty() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
     token_admin_client.mint(&depositor, &5000);
 
     // Lock a bounty first
@@ -497,7 +507,7 @@ fn test_batch_lock_event_emission() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
     token_admin_client.mint(&depositor, &5000);
 
     let initial_event_count = env.events().all().len();
@@ -536,7 +546,7 @@ fn test_batch_release_event_emission() {
     let token_admin = Address::generate(&env);
     let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
     token_admin_client.mint(&depositor, &5000);
 
     // Lock funds
@@ -579,7 +589,7 @@ fn test_complete_bounty_workflow_lock_release() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // 1. Initialize contract
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
 
     // 2. Mint tokens to depositor
     let amount = 5000i128;
@@ -621,7 +631,7 @@ fn test_complete_bounty_workflow_lock_refund() {
     let token_admin = Address::generate(&env);
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
-    client.init(&admin, &token);
+    init_escrow(&client, &admin, &token);
 
     let amount = 5000i128;
     token_admin_client.mint(&depositor, &amount);
@@ -641,6 +651,7 @@ fn test_complete_bounty_workflow_lock_refund() {
         &None::<i128>,
         &None::<Address>,
         &crate::RefundMode::Full,
+        &None::<soroban_sdk::String>,
     );
 
     // Verify funds refunded
@@ -666,7 +677,7 @@ fn test_pause_functionality() {
     let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
 
     // Initialize escrow
-    client.init(&admin, &token_address);
+    init_escrow(&client, &admin, &token_address);
 
     // Initially not paused
     assert_eq!(client.is_paused(), false);
@@ -700,7 +711,7 @@ fn test_emergency_withdraw() {
     let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
 
     // Initialize escrow
-    client.init(&admin, &token_address);
+    init_escrow(&client, &admin, &token_address);
 
     // Pause contract
     client.pause();
@@ -714,3 +725,812 @@ fn test_emergency_withdraw() {
     // Verify pause state still true
     assert_eq!(client.is_paused(), true);
 }
+
+// ========================================================================
+// Anti-Abuse Configuration Event Tests
+// ========================================================================
+
+#[test]
+fn test_rate_limit_config_update_event() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &admin);
+    init_escrow(&client, &admin, &token);
+
+    client.update_rate_limit_config(&7200, &5, &120);
+
+    let (emitter, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(emitter, contract_id);
+    assert_eq!(
+        topics,
+        (symbol_short!("abuse"), symbol_short!("config")).into_val(&env)
+    );
+    let event = AntiAbuseConfigUpdated::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.window_size, 7200);
+    assert_eq!(event.max_operations, 5);
+    assert_eq!(event.cooldown_period, 120);
+    assert_eq!(event.by, admin);
+}
+
+#[test]
+fn test_whitelist_change_event() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &admin);
+    let user = Address::generate(&env);
+    init_escrow(&client, &admin, &token);
+
+    client.set_whitelist(&user, &true);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("abuse"), symbol_short!("wlist")).into_val(&env)
+    );
+    let event = WhitelistChanged::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.address, user);
+    assert!(event.whitelisted);
+    assert_eq!(event.by, admin);
+
+    client.set_whitelist(&user, &false);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event = WhitelistChanged::try_from_val(&env, &data).unwrap();
+    assert!(!event.whitelisted);
+}
+
+#[test]
+fn test_get_whitelisted() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &admin);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    init_escrow(&client, &admin, &token);
+    assert_eq!(client.get_whitelisted().len(), 0);
+
+    client.set_whitelist(&alice, &true);
+    client.set_whitelist(&bob, &true);
+    // Re-adding does not duplicate the entry
+    client.set_whitelist(&alice, &true);
+    assert_eq!(
+        client.get_whitelisted(),
+        vec![&env, alice.clone(), bob.clone()]
+    );
+
+    client.set_whitelist(&alice, &false);
+    assert_eq!(client.get_whitelisted(), vec![&env, bob.clone()]);
+    // Removing an address that isn't listed is a no-op
+    client.set_whitelist(&alice, &false);
+    assert_eq!(client.get_whitelisted(), vec![&env, bob]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")] // UnsupportedToken
+fn test_init_rejects_non_token_address() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let not_a_token = Address::generate(&env);
+    init_escrow(&client, &admin, &not_a_token);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_init_with_fee_config() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, _token_client, _token_admin) = create_token_contract(&env, &admin);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 100,
+        release_fee_rate: 250,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config.clone()));
+
+    assert_eq!(client.get_fee_config(), fee_config);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_init_rejects_invalid_fee_config() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token, _token_client, _token_admin) = create_token_contract(&env, &admin);
+
+    let too_high = FeeConfig {
+        lock_fee_rate: 1_001,
+        release_fee_rate: 0,
+        fee_recipient: admin.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    let res = client.try_init(&admin, &token, &Some(too_high));
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRate)));
+
+    let self_recipient = FeeConfig {
+        lock_fee_rate: 100,
+        release_fee_rate: 100,
+        fee_recipient: contract_id,
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    let res = client.try_init(&admin, &token, &Some(self_recipient));
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRecipient)));
+
+    // Nothing was stored, so a valid init still succeeds
+    init_escrow(&client, &admin, &token);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_simulate_lock() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    assert_eq!(
+        client.try_simulate_lock(&1, &1000, &deadline),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 250,
+        release_fee_rate: 0,
+        fee_recipient: admin.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+
+    assert_eq!(client.simulate_lock(&1, &1000, &deadline), (975, 25));
+    assert_eq!(
+        client.try_simulate_lock(&1, &0, &deadline),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_simulate_lock(&1, &1000, &env.ledger().timestamp()),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    // Nothing moved, and the real lock matches the simulation
+    assert_eq!(token_client.balance(&contract_id), 0);
+    client.lock_funds(&depositor, &1, &1000, &deadline);
+    assert_eq!(token_client.balance(&contract_id), 975);
+    assert_eq!(
+        client.try_simulate_lock(&1, &1000, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+
+    client.pause();
+    assert_eq!(
+        client.try_simulate_lock(&2, &1000, &deadline),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_escrow_records_lock_fee() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, _token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 250,
+        release_fee_rate: 0,
+        fee_recipient: admin.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    client.lock_funds(&depositor, &1, &1000, &deadline);
+
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.gross_amount, 1000);
+    assert_eq!(escrow.lock_fee_paid, 25);
+    assert_eq!(escrow.amount, escrow.gross_amount - escrow.lock_fee_paid);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_fee_rounding_modes() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+
+    // 1% lock fee: 150 -> 1.5, 250 -> 2.5
+    let fee_config = FeeConfig {
+        lock_fee_rate: 100,
+        release_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    let deadline = env.ledger().timestamp() + 1000;
+
+    client.lock_funds(&depositor, &1, &150, &deadline);
+    assert_eq!(token_client.balance(&fee_recipient), 1);
+
+    client.update_fee_config(&None, &None, &None, &None, &Some(FeeRoundingMode::Up));
+    client.lock_funds(&depositor, &2, &150, &deadline);
+    assert_eq!(token_client.balance(&fee_recipient), 3);
+
+    // Ties go to the even neighbour
+    client.update_fee_config(&None, &None, &None, &None, &Some(FeeRoundingMode::Nearest));
+    client.lock_funds(&depositor, &3, &150, &deadline);
+    assert_eq!(token_client.balance(&fee_recipient), 5);
+    client.lock_funds(&depositor, &4, &250, &deadline);
+    assert_eq!(token_client.balance(&fee_recipient), 7);
+    assert_eq!(client.get_escrow_info(&4).amount, 248);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_accrued_fees_withdrawal() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 100,
+        release_fee_rate: 100,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    client.set_fee_accrual(&true);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    // Lock fee of 10 stays in the contract
+    client.lock_funds(&depositor, &1, &1000, &deadline);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+    assert_eq!(client.get_accrued_fees(), 10);
+    assert_eq!(client.get_total_locked(), 990);
+
+    // Release fee of 9 (1% of 990) accrues as well
+    client.release_funds(&1, &contributor);
+    assert_eq!(token_client.balance(&contributor), 981);
+    assert_eq!(client.get_accrued_fees(), 19);
+    assert_eq!(client.get_total_locked(), 0);
+
+    assert_eq!(client.withdraw_accrued_fees(&fee_recipient), 19);
+    assert_eq!(token_client.balance(&fee_recipient), 19);
+    assert_eq!(client.get_accrued_fees(), 0);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_accrued_fees_cannot_touch_escrowed_funds() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 100,
+        release_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    client.set_fee_accrual(&true);
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &1000, &deadline);
+
+    // Drain the contract below what escrows need
+    client.pause();
+    client.emergency_withdraw(&admin);
+    client.unpause();
+    token_admin.mint(&contract_id, &995);
+
+    // Only the 5 above the 990 still escrowed may be withdrawn
+    assert_eq!(client.withdraw_accrued_fees(&fee_recipient), 5);
+    assert_eq!(client.get_accrued_fees(), 5);
+    assert_eq!(token_client.balance(&contract_id), 990);
+
+    let res = client.try_withdraw_accrued_fees(&fee_recipient);
+    assert_eq!(res, Err(Ok(Error::InsufficientFunds)));
+}
+
+#[test]
+fn test_event_verbosity_minimal_emits_only_essential_events() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, _token_client, token_admin) = create_token_contract(&env, &admin);
+    init_escrow(&client, &admin, &token);
+    token_admin.mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let escrow_event_count = || {
+        env.events()
+            .all()
+            .iter()
+            .filter(|(emitter, _, _)| *emitter == contract_id)
+            .count()
+    };
+
+    assert_eq!(client.get_event_verbosity(), EventVerbosity::Full);
+
+    // Full: enhanced, activity and monitoring events
+    let before = escrow_event_count();
+    client.lock_funds(&depositor, &1, &1000, &deadline);
+    assert!(escrow_event_count() - before > 1);
+
+    client.set_event_verbosity(&EventVerbosity::Minimal);
+    let before = escrow_event_count();
+    client.lock_funds(&depositor, &2, &1000, &deadline);
+    assert_eq!(escrow_event_count() - before, 1);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("f_lock"), 2u64).into_val(&env));
+    let event = FundsLocked::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.amount, 1000);
+    assert_eq!(event.depositor, depositor);
+
+    // Indexing still happens in minimal mode
+    client.release_funds(&2, &depositor);
+    assert_eq!(
+        client.get_bounties_by_contributor(&depositor),
+        vec![&env, 2u64]
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_release_after_partial_release_conserves_funds() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 0,
+        release_fee_rate: 1000,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    client.lock_funds(&depositor, &1, &2000, &deadline);
+
+    client.batch_release_partial(&vec![&env, (1u64, contributor.clone(), 500)]);
+    assert_eq!(
+        client.get_escrow_info(&1).status,
+        EscrowStatus::PartiallyReleased
+    );
+
+    // Only the remaining 1500 is released, with the fee charged on it
+    client.release_funds(&1, &contributor);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(token_client.balance(&contributor), 450 + 1350);
+    assert_eq!(token_client.balance(&fee_recipient), 50 + 150);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_total_locked(), 0);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_refund_with_fee_reimbursement() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 500,
+        release_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    client.lock_funds(&depositor, &1, &2000, &deadline);
+    assert_eq!(token_client.balance(&fee_recipient), 100);
+    assert_eq!(token_client.balance(&depositor), 8_000);
+
+    // Before the deadline, at the admin's discretion
+    client.refund_with_fee_reimbursement(&1);
+
+    assert_eq!(token_client.balance(&depositor), 10_000);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, crate::EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 1);
+
+    assert_eq!(
+        client.try_refund_with_fee_reimbursement(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_refund_with_fee_reimbursement_from_accrued_fees() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 500,
+        release_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    client.set_fee_accrual(&true);
+    client.lock_funds(&depositor, &1, &2000, &deadline);
+    client.lock_funds(&depositor, &2, &2000, &deadline);
+    assert_eq!(client.get_accrued_fees(), 200);
+    client.request_release(&1, &contributor);
+
+    // The fee comes out of the accrued balance, not the fee recipient
+    client.refund_with_fee_reimbursement(&1);
+    assert_eq!(token_client.balance(&depositor), 8_000);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+    assert_eq!(client.get_accrued_fees(), 100);
+    assert_eq!(token_client.balance(&contract_id), 2_000);
+    assert_eq!(client.get_release_request(&1), None);
+
+    // Nothing is reimbursed once part of the bounty went to a contributor
+    client.batch_release_partial(&vec![&env, (2u64, contributor.clone(), 500)]);
+    assert_eq!(
+        client.try_refund_with_fee_reimbursement(&2),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_combined_fee_rate_cap() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token, _token_client, _token_admin) = create_token_contract(&env, &admin);
+
+    // Each rate is within bounds on its own, but together they exceed the cap
+    let stacked = FeeConfig {
+        lock_fee_rate: 600,
+        release_fee_rate: 500,
+        fee_recipient: admin.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    assert_eq!(
+        client.try_init(&admin, &token, &Some(stacked)),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+
+    init_escrow(&client, &admin, &token);
+    client.update_fee_config(&Some(600), &Some(400), &None, &Some(true), &None);
+
+    let res = client.try_update_fee_config(&None, &Some(401), &None, &None, &None);
+    assert_eq!(res, Err(Ok(Error::InvalidFeeRate)));
+    let config = client.get_fee_config();
+    assert_eq!(config.lock_fee_rate, 600);
+    assert_eq!(config.release_fee_rate, 400);
+
+    // Lowering one side makes room for the other
+    client.update_fee_config(&Some(100), &Some(900), &None, &None, &None);
+    assert_eq!(client.get_fee_config().release_fee_rate, 900);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_preview_payout_matches_release() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+
+    init_escrow(&client, &admin, &token);
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &1001, &deadline);
+    client.lock_funds(&depositor, &2, &1000, &deadline);
+
+    // No fees configured: the contributor gets everything
+    let preview = client.preview_payout(&1);
+    assert_eq!(preview.gross, 1001);
+    assert_eq!(preview.release_fee, 0);
+    assert_eq!(preview.net, 1001);
+    assert_eq!(preview.fee_recipient, None);
+
+    // 2.5% rounded up on 1001 is 26
+    client.update_fee_config(
+        &None,
+        &Some(250),
+        &Some(fee_recipient.clone()),
+        &Some(true),
+        &Some(FeeRoundingMode::Up),
+    );
+    let preview = client.preview_payout(&1);
+    assert_eq!(preview.release_fee, 26);
+    assert_eq!(preview.net, 975);
+    assert_eq!(preview.fee_recipient, Some(fee_recipient.clone()));
+
+    client.release_funds(&1, &contributor);
+    assert_eq!(token_client.balance(&contributor), preview.net);
+    assert_eq!(token_client.balance(&fee_recipient), preview.release_fee);
+
+    let released = client.preview_payout(&1);
+    assert_eq!((released.gross, released.net), (0, 0));
+
+    // With accrual on, the fee stays in the contract
+    client.set_fee_accrual(&true);
+    assert_eq!(client.preview_payout(&2).fee_recipient, Some(contract_id));
+
+    assert_eq!(
+        client.try_preview_payout(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_batch_release_best_effort_charges_release_fee() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 0,
+        release_fee_rate: 250,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &1000, &deadline);
+
+    let items = vec![
+        &env,
+        crate::ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: contributor.clone(),
+        },
+    ];
+    let result = client.batch_release_best_effort(&items);
+    assert_eq!(result.total_amount, 975);
+    assert_eq!(token_client.balance(&contributor), 975);
+    assert_eq!(token_client.balance(&fee_recipient), 25);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_batch_lock_funds_charges_lock_fee() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &10_000);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 500,
+        release_fee_rate: 0,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    let deadline = env.ledger().timestamp() + 1000;
+
+    // Same fee and escrow record as a single lock_funds
+    client.lock_funds(&depositor, &1, &2000, &deadline);
+    let items = vec![
+        &env,
+        crate::LockFundsItem {
+            bounty_id: 2,
+            depositor: depositor.clone(),
+            amount: 2000,
+            deadline,
+        },
+    ];
+    client.batch_lock_funds(&items);
+
+    let single = client.get_escrow_info(&1);
+    let batched = client.get_escrow_info(&2);
+    assert_eq!(batched.amount, single.amount);
+    assert_eq!(batched.remaining_amount, 1900);
+    assert_eq!(batched.gross_amount, 2000);
+    assert_eq!(batched.lock_fee_paid, 100);
+    assert_eq!(token_client.balance(&fee_recipient), 200);
+    assert_eq!(token_client.balance(&contract_id), 3800);
+    assert_eq!(client.get_total_locked(), 3800);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_partial_release_fees_match_full_release() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &100_000);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 0,
+        release_fee_rate: 250,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &10_000, &deadline);
+    client.lock_funds(&depositor, &2, &10_000, &deadline);
+    client.lock_funds(&depositor, &3, &10_000, &deadline);
+
+    // One full release: 2.5% of 10_000
+    client.release_funds(&1, &contributor);
+    let full_fee = token_client.balance(&fee_recipient);
+    assert_eq!(full_fee, 250);
+
+    // Fees are charged on each released portion and add up to the same total
+    for amount in [3_000, 3_000, 4_000] {
+        client.batch_release_partial(&vec![&env, (2u64, contributor.clone(), amount)]);
+    }
+    assert_eq!(token_client.balance(&fee_recipient) - full_fee, full_fee);
+    assert_eq!(token_client.balance(&contributor), 2 * (10_000 - full_fee));
+    assert_eq!(
+        client.get_escrow_info(&2).status,
+        crate::EscrowStatus::Released
+    );
+
+    // Uneven portions may each round down, losing less than one unit apiece
+    let before = token_client.balance(&fee_recipient);
+    for amount in [3_333, 3_333, 3_334] {
+        client.batch_release_partial(&vec![&env, (3u64, contributor.clone(), amount)]);
+    }
+    let partial_fees = token_client.balance(&fee_recipient) - before;
+    assert!(partial_fees <= full_fee && full_fee - partial_fees < 3);
+
+    // Each release reports the fee on its own portion
+    let mut fee_amounts = vec![&env];
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() == 1
+            && soroban_sdk::Symbol::try_from_val(&env, &topics.get(0).unwrap())
+                == Ok(symbol_short!("fee"))
+        {
+            let event = FeeCollected::try_from_val(&env, &data).unwrap();
+            fee_amounts.push_back(event.amount);
+        }
+    }
+    assert_eq!(fee_amounts, vec![&env, 250i128, 75, 75, 100, 83, 83, 83]);
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn test_batch_release_funds_detailed_reports_net_amounts() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (token, token_client, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&depositor, &100_000);
+
+    let fee_config = FeeConfig {
+        lock_fee_rate: 0,
+        release_fee_rate: 250,
+        fee_recipient: fee_recipient.clone(),
+        fee_enabled: true,
+        rounding_mode: FeeRoundingMode::Down,
+    };
+    client.init(&admin, &token, &Some(fee_config));
+    env.ledger().set_timestamp(1000);
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &10_000, &deadline);
+    env.ledger().set_timestamp(1100);
+    client.lock_funds(&depositor, &2, &4_000, &deadline);
+
+    let items = vec![
+        &env,
+        crate::ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: alice.clone(),
+        },
+        crate::ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: bob.clone(),
+        },
+    ];
+    let released = client.batch_release_funds_detailed(&items);
+
+    // 2.5% release fee on each bounty
+    assert_eq!(released, vec![&env, (1u64, 9_750i128), (2u64, 3_900i128)]);
+    assert_eq!(token_client.balance(&alice), 9_750);
+    assert_eq!(token_client.balance(&bob), 3_900);
+    assert_eq!(token_client.balance(&fee_recipient), 350);
+}
@@ -11,7 +11,7 @@ fn test_escrow_metadata_basic_operations() {
     // Initialize contract
     let admin = Address::generate(&env);
     let token = Address::generate(&env);
-    client.init(&admin, &token);
+    client.init(&admin, &token, &None);
 
     // Lock funds for bounty
     let depositor = Address::generate(&env);
@@ -66,7 +66,7 @@ fn test_escrow_metadata_authorization() {
     // Initialize contract
     let admin = Address::generate(&env);
     let token = Address::generate(&env);
-    client.init(&admin, &token);
+    client.init(&admin, &token, &None);
 
     // Lock funds
     let depositor = Address::generate(&env);
@@ -100,7 +100,7 @@ fn test_escrow_metadata_size_limits() {
     // Initialize contract
     let admin = Address::generate(&env);
     let token = Address::generate(&env);
-    client.init(&admin, &token);
+    client.init(&admin, &token, &None);
 
     // Lock funds
     let depositor = Address::generate(&env);
@@ -139,7 +139,7 @@ fn test_escrow_metadata_optional_fields() {
     // Initialize contract
     let admin = Address::generate(&env);
     let token = Address::generate(&env);
-    client.init(&admin, &token);
+    client.init(&admin, &token, &None);
 
     // Lock funds
     let depositor = Address::generate(&env);
@@ -173,7 +173,7 @@ fn test_escrow_nonexistent_bounty() {
     // Initialize contract
     let admin = Address::generate(&env);
     let token = Address::generate(&env);
-    client.init(&admin, &token);
+    client.init(&admin, &token, &None);
 
     // Try to get metadata for non-existent bounty
     let result = client.try_get_escrow_metadata(&999u64);
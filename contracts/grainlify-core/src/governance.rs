@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, Symbol, symbol_short};
+use soroban_sdk::{contracttype, token, Address, BytesN, Map, Symbol, Val, Vec, symbol_short};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -26,12 +26,24 @@ pub enum VotingScheme {
     TokenWeighted,
 }
 
+/// What an approved proposal does when executed
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum ProposalKind {
+    /// Upgrade this contract to the given WASM hash
+    Upgrade(BytesN<32>),
+    /// Set a named governance parameter
+    SetParameter(Symbol, i128),
+    /// Invoke `function` on another contract with the given arguments
+    CallContract(Address, Symbol, Vec<Val>),
+}
+
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct Proposal {
     pub id: u32,
     pub proposer: Address,
-    pub new_wasm_hash: BytesN<32>,
+    pub kind: ProposalKind,
     pub description: Symbol,
     pub created_at: u64,
     pub voting_start: u64,
@@ -42,6 +54,22 @@ pub struct Proposal {
     pub votes_against: i128,
     pub votes_abstain: i128,
     pub total_votes: u32,
+    pub stake: i128, // Amount of the stake token locked by the proposer
+    pub stake_token: Option<Address>, // Token the stake was locked in, settled against at finalize
+}
+
+/// Payload of the `proposal` event: the full voting timeline, so indexers
+/// don't need to read the proposal back
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProposalCreated {
+    pub proposal_id: u32,
+    pub proposer: Address,
+    pub description: Symbol,
+    pub created_at: u64,
+    pub voting_start: u64,
+    pub voting_end: u64,
+    pub execution_delay: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -53,6 +81,8 @@ pub struct GovernanceConfig {
     pub approval_threshold: u32,  // Basis points (e.g., 6667 = 66.67%)
     pub min_proposal_stake: i128,
     pub voting_scheme: VotingScheme,
+    /// Token locked as the proposal stake; `None` only checks voting power
+    pub stake_token: Option<Address>,
 }
 
 #[derive(Clone, Debug)]
@@ -71,6 +101,7 @@ pub const PROPOSAL_COUNT: Symbol = symbol_short!("PROP_CNT");
 pub const VOTES: Symbol = symbol_short!("VOTES");
 pub const GOVERNANCE_CONFIG: Symbol = symbol_short!("GOV_CFG");
 pub const VOTER_REGISTRY: Symbol = symbol_short!("VOTERS");
+pub const PARAMETERS: Symbol = symbol_short!("PARAMS");
 
 #[soroban_sdk::contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -132,6 +163,21 @@ impl GovernanceContract {
         proposer: Address,
         new_wasm_hash: BytesN<32>,
         description: Symbol,
+    ) -> Result<u32, Error> {
+        Self::create_proposal_with_kind(
+            env,
+            proposer,
+            ProposalKind::Upgrade(new_wasm_hash),
+            description,
+        )
+    }
+
+    /// Create a new proposal of any kind
+    pub fn create_proposal_with_kind(
+        env: &soroban_sdk::Env,
+        proposer: Address,
+        kind: ProposalKind,
+        description: Symbol,
     ) -> Result<u32, Error> {
         // Authenticate proposer
         proposer.require_auth();
@@ -148,6 +194,19 @@ impl GovernanceContract {
         if proposer_balance < config.min_proposal_stake {
             return Err(Error::InsufficientStake);
         }
+
+        // Lock the stake until the proposal is finalized
+        let (stake, stake_token) = match &config.stake_token {
+            Some(stake_token) if config.min_proposal_stake > 0 => {
+                token::Client::new(env, stake_token).transfer(
+                    &proposer,
+                    &env.current_contract_address(),
+                    &config.min_proposal_stake,
+                );
+                (config.min_proposal_stake, Some(stake_token.clone()))
+            }
+            _ => (0, None),
+        };
         
         // Get current proposal count
         let proposal_id: u32 = env
@@ -162,7 +221,7 @@ impl GovernanceContract {
         let proposal = Proposal {
             id: proposal_id,
             proposer: proposer.clone(),
-            new_wasm_hash,
+            kind,
             description: description.clone(),
             created_at: current_time,
             voting_start: current_time,
@@ -173,6 +232,8 @@ impl GovernanceContract {
             votes_against: 0,
             votes_abstain: 0,
             total_votes: 0,
+            stake,
+            stake_token,
         };
         
         // Store proposal
@@ -193,12 +254,29 @@ impl GovernanceContract {
         // Emit event
         env.events().publish(
             (symbol_short!("proposal"), proposer.clone()),
-            (proposal_id, description),
+            ProposalCreated {
+                proposal_id,
+                proposer: proposer.clone(),
+                description,
+                created_at: proposal.created_at,
+                voting_start: proposal.voting_start,
+                voting_end: proposal.voting_end,
+                execution_delay: proposal.execution_delay,
+            },
         );
         
         Ok(proposal_id)
     }
     
+    /// Get a parameter set by an executed `SetParameter` proposal
+    pub fn get_parameter(env: &soroban_sdk::Env, key: Symbol) -> Option<i128> {
+        let params: Map<Symbol, i128> = env
+            .storage()
+            .instance()
+            .get(&PARAMETERS)?;
+        params.get(key)
+    }
+
     /// Get voting power for an address
     pub fn get_voting_power(_env: &soroban_sdk::Env, _voter: &Address) -> Result<i128, Error> {
         // TODO: Integrate with token contract or use native balance
@@ -337,35 +415,20 @@ impl GovernanceContract {
             .instance()
             .get(&GOVERNANCE_CONFIG)
             .ok_or(Error::NotInitialized)?;
-        
-        // Calculate total possible votes (placeholder for now)
-        let total_possible_votes = 1000i128; 
-        
-        let total_cast_votes = proposal.votes_for + proposal.votes_against + proposal.votes_abstain;
-        
-        // Check quorum
-        let quorum_met = (total_cast_votes * 10000) / total_possible_votes >= config.quorum_percentage as i128;
-        
-        if !quorum_met {
-            proposal.status = ProposalStatus::Rejected;
-            proposals.set(proposal_id, proposal.clone());
-            env.storage().instance().set(&PROPOSALS, &proposals);
-            return Ok(ProposalStatus::Rejected);
-        }
-        
-        // Check approval threshold (excluding abstentions)
-        let votes_cast_for_or_against = proposal.votes_for + proposal.votes_against;
-        
-        if votes_cast_for_or_against == 0 {
+
+        let (quorum_met, would_pass, _) = Self::compute_result(&proposal, &config);
+
+        // No quorum, or only abstentions: rejected outright
+        if !quorum_met || proposal.votes_for + proposal.votes_against == 0 {
             proposal.status = ProposalStatus::Rejected;
             proposals.set(proposal_id, proposal.clone());
             env.storage().instance().set(&PROPOSALS, &proposals);
+            // Failing quorum forfeits the stake
+            Self::settle_stake(&env, &proposal, !quorum_met);
             return Ok(ProposalStatus::Rejected);
         }
-        
-        let approval_percentage = (proposal.votes_for * 10000) / votes_cast_for_or_against;
-        
-        if approval_percentage >= config.approval_threshold as i128 {
+
+        if would_pass {
             proposal.status = ProposalStatus::Approved;
         } else {
             proposal.status = ProposalStatus::Rejected;
@@ -373,6 +436,7 @@ impl GovernanceContract {
         
         proposals.set(proposal_id, proposal.clone());
         env.storage().instance().set(&PROPOSALS, &proposals);
+        Self::settle_stake(&env, &proposal, false);
         
         // Emit event
         env.events().publish(
@@ -383,6 +447,112 @@ impl GovernanceContract {
         Ok(proposal.status)
     }
     
+    /// Finalize up to `limit` `Active` proposals whose voting period has
+    /// ended, lowest id first. Callable by anyone (e.g. a keeper); returns
+    /// how many proposals were finalized.
+    pub fn finalize_expired(env: soroban_sdk::Env, limit: u32) -> Result<u32, Error> {
+        let proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .unwrap_or(soroban_sdk::Map::new(&env));
+
+        let current_time = env.ledger().timestamp();
+        let mut processed = 0u32;
+        for (proposal_id, proposal) in proposals.iter() {
+            if processed >= limit {
+                break;
+            }
+            if proposal.status == ProposalStatus::Active && current_time > proposal.voting_end {
+                Self::finalize_proposal(env.clone(), proposal_id)?;
+                processed += 1;
+            }
+        }
+
+        Ok(processed)
+    }
+
+    /// Return a finalized proposal's stake to its proposer, or keep it in the
+    /// contract when `slash` is set.
+    ///
+    /// Settles in the token recorded on the proposal, not the live config, so
+    /// changing `stake_token` later can't strand or swap locked stakes.
+    /// Slashed stakes stay in the contract's balance; the only way to move
+    /// them is a `CallContract` proposal that transfers them out.
+    fn settle_stake(env: &soroban_sdk::Env, proposal: &Proposal, slash: bool) {
+        let stake_token = match &proposal.stake_token {
+            Some(stake_token) if proposal.stake > 0 => stake_token,
+            _ => return,
+        };
+
+        if slash {
+            env.events().publish(
+                (symbol_short!("stk_slash"), proposal.id),
+                (proposal.proposer.clone(), proposal.stake),
+            );
+        } else {
+            token::Client::new(env, stake_token).transfer(
+                &env.current_contract_address(),
+                &proposal.proposer,
+                &proposal.stake,
+            );
+            env.events().publish(
+                (symbol_short!("stk_ret"), proposal.id),
+                (proposal.proposer.clone(), proposal.stake),
+            );
+        }
+    }
+
+    /// Quorum and approval math for a proposal's current tallies.
+    ///
+    /// Returns `(quorum_met, would_pass, approval_percentage)`, with the
+    /// approval percentage in basis points of For votes over For + Against.
+    fn compute_result(proposal: &Proposal, config: &GovernanceConfig) -> (bool, bool, u32) {
+        // Calculate total possible votes (placeholder for now)
+        let total_possible_votes = 1000i128;
+
+        let total_cast_votes = proposal.votes_for + proposal.votes_against + proposal.votes_abstain;
+
+        // Check quorum
+        let quorum_met = (total_cast_votes * 10000) / total_possible_votes >= config.quorum_percentage as i128;
+
+        // Check approval threshold (excluding abstentions)
+        let votes_cast_for_or_against = proposal.votes_for + proposal.votes_against;
+        if votes_cast_for_or_against == 0 {
+            return (quorum_met, false, 0);
+        }
+
+        let approval_percentage = (proposal.votes_for * 10000) / votes_cast_for_or_against;
+        let would_pass = quorum_met && approval_percentage >= config.approval_threshold as i128;
+
+        (quorum_met, would_pass, approval_percentage as u32)
+    }
+
+    /// Preview the outcome of a proposal against its current tallies.
+    /// Unlike `finalize_proposal`, this works at any time and changes nothing.
+    pub fn preview_result(
+        env: &soroban_sdk::Env,
+        proposal_id: u32,
+    ) -> Result<(bool, bool, u32), Error> {
+        let proposals: soroban_sdk::Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&PROPOSALS)
+            .ok_or(Error::ProposalsNotFound)?;
+
+        let proposal = proposals
+            .get(proposal_id)
+            .ok_or(Error::ProposalNotFound)?;
+
+        let config: GovernanceConfig = env
+            .storage()
+            .instance()
+            .get(&GOVERNANCE_CONFIG)
+            .ok_or(Error::NotInitialized)?;
+
+        Ok(Self::compute_result(&proposal, &config))
+    }
+
     /// Execute an approved proposal
     pub fn execute_proposal(
         env: soroban_sdk::Env,
@@ -425,13 +595,29 @@ impl GovernanceContract {
             return Err(Error::ProposalExpired);
         }
         
-        // Execute the upgrade (disabled in tests if causing issues, or use dummy)
-        // env.deployer().update_current_contract_wasm(proposal.new_wasm_hash.clone());
-        
-        // Mark as executed
+        // Mark as executed before dispatching so a called contract can't re-execute it
         proposal.status = ProposalStatus::Executed;
-        proposals.set(proposal_id, proposal);
+        proposals.set(proposal_id, proposal.clone());
         env.storage().instance().set(&PROPOSALS, &proposals);
+
+        match proposal.kind {
+            ProposalKind::Upgrade(_new_wasm_hash) => {
+                // Execute the upgrade (disabled in tests if causing issues, or use dummy)
+                // env.deployer().update_current_contract_wasm(_new_wasm_hash);
+            }
+            ProposalKind::SetParameter(key, value) => {
+                let mut params: Map<Symbol, i128> = env
+                    .storage()
+                    .instance()
+                    .get(&PARAMETERS)
+                    .unwrap_or(Map::new(&env));
+                params.set(key, value);
+                env.storage().instance().set(&PARAMETERS, &params);
+            }
+            ProposalKind::CallContract(contract, function, args) => {
+                env.invoke_contract::<Val>(&contract, &function, args);
+            }
+        }
         
         // Emit event
         env.events().publish(
@@ -162,7 +162,8 @@ mod governance;
 mod test;
 use multisig::MultiSig;
 pub use governance::{
-    Error as GovError, Proposal, ProposalStatus, VoteType, VotingScheme, GovernanceConfig, Vote
+    Error as GovError, Proposal, ProposalCreated, ProposalKind, ProposalStatus, VoteType,
+    VotingScheme, GovernanceConfig, Vote
 };
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec, String,
@@ -545,6 +546,21 @@ impl GrainlifyContract {
         governance::GovernanceContract::create_proposal(&env, proposer, new_wasm_hash, description)
     }
 
+    /// Create a proposal to upgrade, set a parameter, or call another contract
+    pub fn create_proposal_with_kind(
+        env: Env,
+        proposer: Address,
+        kind: governance::ProposalKind,
+        description: Symbol,
+    ) -> Result<u32, governance::Error> {
+        governance::GovernanceContract::create_proposal_with_kind(&env, proposer, kind, description)
+    }
+
+    /// Get a governance parameter set by an executed proposal
+    pub fn get_gov_parameter(env: Env, key: Symbol) -> Option<i128> {
+        governance::GovernanceContract::get_parameter(&env, key)
+    }
+
     /// Cast a vote on a proposal
     pub fn cast_vote(
         env: Env,
@@ -563,6 +579,19 @@ impl GrainlifyContract {
         governance::GovernanceContract::finalize_proposal(env, proposal_id)
     }
 
+    /// Finalize up to `limit` proposals whose voting has ended; returns how many were finalized
+    pub fn finalize_expired(env: Env, limit: u32) -> Result<u32, governance::Error> {
+        governance::GovernanceContract::finalize_expired(env, limit)
+    }
+
+    /// Preview `(quorum_met, would_pass, approval_percentage)` for a proposal's current votes
+    pub fn preview_result(
+        env: Env,
+        proposal_id: u32,
+    ) -> Result<(bool, bool, u32), governance::Error> {
+        governance::GovernanceContract::preview_result(&env, proposal_id)
+    }
+
     /// Execute a proposal
     pub fn execute_proposal(
         env: Env,
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
-use crate::{GrainlifyContract, GrainlifyContractClient, GovernanceConfig, VotingScheme, VoteType, ProposalStatus};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, BytesN, symbol_short};
+use crate::{GrainlifyContract, GrainlifyContractClient, GovernanceConfig, VotingScheme, VoteType, ProposalCreated, ProposalKind, ProposalStatus};
+use soroban_sdk::{contract, contractimpl, testutils::{Address as _, Events, Ledger}, token, vec, Address, Env, BytesN, IntoVal, TryFromVal, symbol_short};
 
 #[test]
 fn test_governance_full_flow() {
@@ -23,6 +23,7 @@ fn test_governance_full_flow() {
         approval_threshold: 6000, // 60%
         min_proposal_stake: 10,
         voting_scheme: VotingScheme::OnePersonOneVote,
+        stake_token: None,
     };
 
     // Initialize
@@ -69,6 +70,7 @@ fn test_governance_approval_and_execution() {
         approval_threshold: 5000,
         min_proposal_stake: 0,
         voting_scheme: VotingScheme::OnePersonOneVote,
+        stake_token: None,
     };
 
     client.init_governance(&admin, &config);
@@ -93,3 +95,244 @@ fn test_governance_approval_and_execution() {
     // Execute
     client.execute_proposal(&voter1, &proposal_id);
 }
+
+fn setup_passing_governance(env: &Env) -> (GrainlifyContractClient<'static>, Address) {
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 0,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        stake_token: None,
+    };
+    client.init_governance(&admin, &config);
+    (client, admin)
+}
+
+fn pass_and_execute(env: &Env, client: &GrainlifyContractClient, proposal_id: u32) {
+    let voter = Address::generate(env);
+    client.cast_vote(&voter, &proposal_id, &VoteType::For);
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Approved);
+    env.ledger().set_timestamp(3602 + 1801);
+    client.execute_proposal(&voter, &proposal_id);
+}
+
+#[test]
+fn test_governance_set_parameter_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_passing_governance(&env);
+
+    let kind = ProposalKind::SetParameter(symbol_short!("fee_rate"), 250);
+    let proposal_id = client.create_proposal_with_kind(&admin, &kind, &symbol_short!("FEE"));
+    assert_eq!(client.get_gov_parameter(&symbol_short!("fee_rate")), None);
+
+    pass_and_execute(&env, &client, proposal_id);
+    assert_eq!(client.get_gov_parameter(&symbol_short!("fee_rate")), Some(250));
+}
+
+#[contract]
+pub struct ParamTarget;
+
+#[contractimpl]
+impl ParamTarget {
+    pub fn set_fee(env: Env, rate: i128) {
+        env.storage().instance().set(&symbol_short!("fee"), &rate);
+    }
+
+    pub fn fee(env: Env) -> i128 {
+        env.storage().instance().get(&symbol_short!("fee")).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_governance_call_contract_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_passing_governance(&env);
+    let target_id = env.register_contract(None, ParamTarget);
+    let target = ParamTargetClient::new(&env, &target_id);
+
+    let args = vec![&env, 300i128.into_val(&env)];
+    let kind = ProposalKind::CallContract(target_id.clone(), symbol_short!("set_fee"), args);
+    let proposal_id = client.create_proposal_with_kind(&admin, &kind, &symbol_short!("CALL"));
+
+    pass_and_execute(&env, &client, proposal_id);
+    assert_eq!(target.fee(), 300);
+}
+
+#[test]
+fn test_proposal_event_includes_timeline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1000);
+    let (client, admin) = setup_passing_governance(&env);
+
+    let wasm_hash = BytesN::from_array(&env, &[3u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("proposal"), admin.clone()).into_val(&env));
+    let event = ProposalCreated::try_from_val(&env, &data).unwrap();
+    assert_eq!(
+        event,
+        ProposalCreated {
+            proposal_id,
+            proposer: admin,
+            description: symbol_short!("UPGRADE"),
+            created_at: 1000,
+            voting_start: 1000,
+            voting_end: 1000 + 3600,
+            execution_delay: 1800,
+        }
+    );
+}
+
+#[test]
+fn test_preview_result_during_voting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_passing_governance(&env);
+
+    let wasm_hash = BytesN::from_array(&env, &[4u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+
+    // No votes yet: quorum of 10 bps needs at least one of 1000 possible votes
+    assert_eq!(client.preview_result(&proposal_id), (false, false, 0));
+
+    client.cast_vote(&Address::generate(&env), &proposal_id, &VoteType::For);
+    assert_eq!(client.preview_result(&proposal_id), (true, true, 10000));
+
+    client.cast_vote(&Address::generate(&env), &proposal_id, &VoteType::Against);
+    client.cast_vote(&Address::generate(&env), &proposal_id, &VoteType::Against);
+    assert_eq!(client.preview_result(&proposal_id), (true, false, 3333));
+
+    // Preview doesn't finalize anything
+    let res = client.try_finalize_proposal(&proposal_id);
+    assert!(res.is_err());
+    assert!(client.try_preview_result(&99).is_err());
+}
+
+fn setup_staked_governance(
+    env: &Env,
+    quorum_percentage: u32,
+) -> (GrainlifyContractClient<'static>, Address, token::Client<'static>) {
+    let contract_id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let stake_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        quorum_percentage,
+        approval_threshold: 5000,
+        min_proposal_stake: 50,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        stake_token: Some(stake_token.clone()),
+    };
+    client.init_governance(&admin, &config);
+    token::StellarAssetClient::new(env, &stake_token).mint(&admin, &100);
+    (client, admin, token::Client::new(env, &stake_token))
+}
+
+#[test]
+fn test_proposal_stake_locked_and_returned() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, stake_token) = setup_staked_governance(&env, 10);
+
+    let wasm_hash = BytesN::from_array(&env, &[6u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+    assert_eq!(stake_token.balance(&admin), 50);
+    assert_eq!(stake_token.balance(&client.address), 50);
+
+    client.cast_vote(&Address::generate(&env), &proposal_id, &VoteType::For);
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Approved);
+    assert_eq!(stake_token.balance(&admin), 100);
+    assert_eq!(stake_token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_proposal_stake_slashed_without_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, stake_token) = setup_staked_governance(&env, 5000);
+
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+    client.cast_vote(&Address::generate(&env), &proposal_id, &VoteType::For);
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Rejected);
+    assert_eq!(stake_token.balance(&admin), 50);
+    assert_eq!(stake_token.balance(&client.address), 50);
+}
+
+#[test]
+fn test_proposal_stake_settles_in_locked_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, stake_token) = setup_staked_governance(&env, 10);
+
+    let wasm_hash = BytesN::from_array(&env, &[8u8; 32]);
+    let proposal_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+    assert_eq!(stake_token.balance(&client.address), 50);
+
+    // Reconfiguring the stake token doesn't affect stakes already locked
+    let config = GovernanceConfig {
+        voting_period: 3600,
+        execution_delay: 1800,
+        quorum_percentage: 10,
+        approval_threshold: 5000,
+        min_proposal_stake: 50,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        stake_token: None,
+    };
+    client.init_governance(&admin, &config);
+
+    client.cast_vote(&Address::generate(&env), &proposal_id, &VoteType::For);
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_proposal(&proposal_id), ProposalStatus::Approved);
+    assert_eq!(stake_token.balance(&admin), 100);
+    assert_eq!(stake_token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_finalize_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_passing_governance(&env);
+
+    let mut ids = [0u32; 3];
+    for (i, id) in ids.iter_mut().enumerate() {
+        let wasm_hash = BytesN::from_array(&env, &[i as u8; 32]);
+        *id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+        client.cast_vote(&Address::generate(&env), id, &VoteType::For);
+    }
+    assert_eq!(client.finalize_expired(&10), 0);
+
+    // A proposal created later is still in its voting period
+    env.ledger().set_timestamp(3000);
+    let wasm_hash = BytesN::from_array(&env, &[9u8; 32]);
+    let late_id = client.create_proposal(&admin, &wasm_hash, &symbol_short!("UPGRADE"));
+
+    env.ledger().set_timestamp(3602);
+    assert_eq!(client.finalize_expired(&2), 2);
+    assert_eq!(client.finalize_expired(&10), 1);
+    assert_eq!(client.finalize_expired(&10), 0);
+
+    for id in ids {
+        assert!(client.try_finalize_proposal(&id).is_err());
+    }
+    // Still active, so it can be finalized manually once voting ends
+    env.ledger().set_timestamp(3000 + 3601);
+    assert_eq!(client.finalize_proposal(&late_id), ProposalStatus::Rejected);
+}